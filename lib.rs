@@ -16,6 +16,11 @@ mod geode_marketplace {
     use ink::storage::StorageVec;
     use ink::env::hash::{Sha2x256, HashOutput};
 
+    // how long (in milliseconds) a buyer has to escalate a denied problem to the arbiters
+    const ESCALATION_WINDOW: u64 = 86400000;
+    // how long (in milliseconds) a seller has to ship a physical order when the product sets no handling_time (30 days)
+    const DEFAULT_HANDLING_TIME: u64 = 2592000000;
+
     // PRELIMINARY STORAGE STRUCTURES >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
     #[derive(Clone, Debug, PartialEq, Eq, Default)]
@@ -58,8 +63,18 @@ mod geode_marketplace {
         digital_file_url: Vec<u8>,
         zeno_percent: u128,
         // must be 0-100, default is 0
-        zeno_buyers: Vec<AccountId>
-        // tracks the first 20 buyers for zeno's incentive
+        zeno_buyers: Vec<AccountId>,
+        // tracks the first zeno_max_buyers buyers for zeno's incentive
+        zeno_max_buyers: u128,
+        // must be 1-100, default is 20
+        zeno_curve: u8,
+        // how the zeno total is split across slots: 0 = halving, 1 = linear, 2 = equal split
+        zeno_unique_buyers: bool,
+        // if true, repeat purchases by one account only take one zeno slot
+        handling_time: u64,
+        // ms the seller has to ship a physical order, 0 = DEFAULT_HANDLING_TIME (30 days)
+        dispute_window: u64
+        // ms the buyer has to report a problem, 0 = the seller's policy
     }
 
     impl Default for Product {
@@ -85,7 +100,12 @@ mod geode_marketplace {
                 product_location: <Vec<u8>>::default(),
                 digital_file_url: <Vec<u8>>::default(),
                 zeno_percent: 0,
-                zeno_buyers: <Vec<AccountId>>::default()
+                zeno_buyers: <Vec<AccountId>>::default(),
+                zeno_max_buyers: 20,
+                zeno_curve: 0,
+                zeno_unique_buyers: false,
+                handling_time: u64::default(),
+                dispute_window: u64::default()
             }
         }
     }
@@ -115,8 +135,18 @@ mod geode_marketplace {
         // include zip code, city, state, country, etc
         zeno_percent: u128,
         // must be 0-100, default is 0
-        zeno_buyers: Vec<AccountId>
-        // tracks the first 20 buyers for zeno's incentive
+        zeno_buyers: Vec<AccountId>,
+        // tracks the first zeno_max_buyers buyers for zeno's incentive
+        zeno_max_buyers: u128,
+        // must be 1-100, default is 20
+        zeno_curve: u8,
+        // how the zeno total is split across slots: 0 = halving, 1 = linear, 2 = equal split
+        zeno_unique_buyers: bool,
+        // if true, repeat purchases by one account only take one zeno slot
+        handling_time: u64,
+        // ms the seller has to ship a physical order, 0 = DEFAULT_HANDLING_TIME (30 days)
+        dispute_window: u64
+        // ms the buyer has to report a problem, 0 = the seller's policy
     }
 
     impl Default for PublicProduct {
@@ -141,7 +171,12 @@ mod geode_marketplace {
                 delivery_info: <Vec<u8>>::default(),
                 product_location: <Vec<u8>>::default(),
                 zeno_percent: 0,
-                zeno_buyers: <Vec<AccountId>>::default()
+                zeno_buyers: <Vec<AccountId>>::default(),
+                zeno_max_buyers: 20,
+                zeno_curve: 0,
+                zeno_unique_buyers: false,
+                handling_time: u64::default(),
+                dispute_window: u64::default()
             }
         }
     }
@@ -169,8 +204,16 @@ mod geode_marketplace {
         // include zip code, city, state, country, etc 
         zeno_percent: u128,
         // must be 0-100, default is 0
-        zeno_buyers: Vec<AccountId>
-        // tracks the first 20 buyers for zeno's incentive
+        zeno_buyers: Vec<AccountId>,
+        // tracks the first zeno_max_buyers buyers for zeno's incentive
+        zeno_max_buyers: u128,
+        // must be 1-100, default is 20
+        zeno_curve: u8,
+        // how the zeno total is split across slots: 0 = halving, 1 = linear, 2 = equal split
+        zeno_unique_buyers: bool,
+        // if true, repeat purchases by one account only take one zeno slot
+        dispute_window: u64
+        // ms the buyer has to report a problem, 0 = the seller's policy
     }
 
     impl Default for Service {
@@ -193,7 +236,11 @@ mod geode_marketplace {
                 booking_link: <Vec<u8>>::default(),
                 service_location: <Vec<u8>>::default(),
                 zeno_percent: 0,
-                zeno_buyers: <Vec<AccountId>>::default()
+                zeno_buyers: <Vec<AccountId>>::default(),
+                zeno_max_buyers: 20,
+                zeno_curve: 0,
+                zeno_unique_buyers: false,
+                dispute_window: u64::default()
             }
         }
     }
@@ -285,7 +332,9 @@ mod geode_marketplace {
         buyer: AccountId,
         cart_total: Balance,
         total_items: u128,
-        cart_items: Vec<(Hash, u128)>
+        cart_items: Vec<(Hash, u128)>,
+        cart_prices: Vec<(Hash, Balance)>
+        // the unit price of each item when it was added to the cart
     }
 
     impl Default for UnpaidCart {
@@ -294,11 +343,110 @@ mod geode_marketplace {
                 buyer: AccountId::from([0x0; 32]),
                 cart_total: Balance::default(), 
                 total_items: 0,
-                cart_items: <Vec<(Hash, u128)>>::default()
+                cart_items: <Vec<(Hash, u128)>>::default(),
+                cart_prices: <Vec<(Hash, Balance)>>::default()
             }
         }
     }
 
+    // one entry in an order's timeline
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct StatusChange {
+        status: OrderStatus,
+        timestamp: u64,
+        actor: AccountId,
+        note: Vec<u8>,
+    }
+
+    // where an order is in its lifecycle
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum OrderStatus {
+        // waiting for the seller to ship
+        #[default]
+        Awaiting,
+        Shipped,
+        Delivered,
+        Complete,
+        // the buyer reported a problem
+        Problem,
+        // the seller refused the order, or it missed its handling deadline
+        Refused,
+        // the buyer cancelled before it shipped
+        Cancelled,
+        // the seller asked for a faulty item back
+        ReturnAuthorized,
+        // the buyer sent the item back
+        ReturnShipped,
+    }
+
+    // the problem a buyer reported on an order
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum ProblemKind {
+        #[default]
+        None,
+        Damaged,
+        WrongItem,
+        NotReceived,
+        NotAsDescribed,
+        Counterfeit,
+        MissingParts,
+        // only for service orders
+        ServiceNotRendered,
+        // only for digital products, the file is broken or missing
+        BrokenFile,
+    }
+
+    // how the seller (or an arbiter) settled a problem
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum Resolution {
+        #[default]
+        None,
+        Refunded,
+        Replaced,
+        Denied,
+        // the item came back and the buyer was refunded
+        ReturnedAndRefunded,
+    }
+
+    // where a denied problem stands with the arbiters
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum Arbitration {
+        #[default]
+        None,
+        Escalated,
+        // the arbiter ruled for the buyer and refunded them
+        RuledRefund,
+        // the arbiter upheld the seller's denial
+        Upheld,
+    }
+
+    // why an account's claimable balance went up
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum CreditReason {
+        #[default]
+        Sale,
+        // zeno affiliate payment
+        Zeno,
+        Refund,
+        BondReturned,
+        // compensation from a seller's bond
+        BondCompensation,
+        // buyer protection claim
+        ProtectionClaim,
+    }
+
     #[derive(Clone, Debug, PartialEq, Eq)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
@@ -320,17 +468,54 @@ mod geode_marketplace {
         deliver_to_address: Vec<u8>,
         deliver_to_account: AccountId,
         tracking_info: Vec<u8>,
-        order_status: u8, 
+        order_status: OrderStatus, 
+        time_shipped: u64,
         time_delivered: u64,
         discussion: Vec<MessageDetails>,
-        problem: u8,
-        resolution: u8,
-        zeno_total: Balance
+        problem: ProblemKind,
+        resolution: Resolution,
+        zeno_total: Balance,
+        platform_fee: Balance,
+        refunded_amount: Balance,
+        refund_offered: Balance,
+        restocked: bool,
+        handling_deadline: u64,
+        delivery_deadline: u64,
+        time_denied: u64,
+        arbitration: Arbitration,
+        arbiter: AccountId,
+        return_address: Vec<u8>,
+        return_tracking: Vec<u8>,
+        return_refund_held: Balance,
+        time_return_authorized: u64,
+        time_return_shipped: u64,
+        time_return_received: u64,
+        timeline: Vec<StatusChange>,
+        is_digital: bool,
+        is_service: bool,
+        service_date: u64,
+        time_service_completed: u64,
+        dispute_window: u64,
+        protection_fee: Balance,
+        zeno_max_buyers: u128,
+        zeno_curve: u8
     }
-    // order_status code: 0 = awaiting seller confirmation, 1 = shipped, 2 = delivered, 3 = complete, 4 = problem, 5 = refused
     // a seller refusing an order triggers a refund to the buyer for that item
-    // problem code: 0 = none, 1 = damaged, 2 = wrong item, 3 = did not receive
-    // resolution code: 0 = none, 1 = refunded, 2 = replaced, 3 = resolution denied
+    // a buyer cancelling an awaiting order does the same, cancelled orders sit in the seller's refused list
+    // returns stay in the seller's problem list until refunded
+    // refund_offered: a partial refund waiting for the buyer to accept
+    // restocked: the seller put the returned items from a refunded order back in inventory
+    // handling_deadline: when a physical order must ship by before anyone can trigger a refund, 0 = none
+    // delivery_deadline: when a shipped order must be marked delivered by, set when it ships
+    // arbitration: whether a denied problem went to an arbiter and how they ruled
+    // the buyer has 24 hours from time_denied to escalate a denied problem to an arbiter
+    // return_refund_held: what the seller paid in when authorizing a return, on top of any escrow
+    // timeline: the 20 most recent status changes and dispute steps on the order, oldest first
+    // service_date: when the seller scheduled a service, time_service_completed: when they marked it done
+    // the problem window on a service starts at completion, or else at the scheduled date
+    // dispute_window: how long the problem window lasts, copied from the listing or seller at checkout
+    // protection_fee: the slice of the seller's payout set aside for the buyer protection pool
+    // zeno_max_buyers, zeno_curve: the listing's zeno settings at checkout, used to split the zeno payout
 
     impl Default for Order {
         fn default() -> Order {
@@ -352,12 +537,37 @@ mod geode_marketplace {
                 deliver_to_address: <Vec<u8>>::default(),
                 deliver_to_account: AccountId::from([0x0; 32]),
                 tracking_info: <Vec<u8>>::default(),
-                order_status: 0, 
+                order_status: OrderStatus::Awaiting, 
+                time_shipped: u64::default(),
                 time_delivered: u64::default(),
                 discussion: <Vec<MessageDetails>>::default(),
-                problem: 0,
-                resolution: 0,
-                zeno_total: Balance::default()
+                problem: ProblemKind::None,
+                resolution: Resolution::None,
+                zeno_total: Balance::default(),
+                platform_fee: Balance::default(),
+                refunded_amount: Balance::default(),
+                refund_offered: Balance::default(),
+                restocked: false,
+                handling_deadline: u64::default(),
+                delivery_deadline: u64::default(),
+                time_denied: u64::default(),
+                arbitration: Arbitration::None,
+                arbiter: AccountId::from([0x0; 32]),
+                return_address: <Vec<u8>>::default(),
+                return_tracking: <Vec<u8>>::default(),
+                return_refund_held: Balance::default(),
+                time_return_authorized: u64::default(),
+                time_return_shipped: u64::default(),
+                time_return_received: u64::default(),
+                timeline: <Vec<StatusChange>>::default(),
+                is_digital: false,
+                is_service: false,
+                service_date: u64::default(),
+                time_service_completed: u64::default(),
+                dispute_window: u64::default(),
+                protection_fee: Balance::default(),
+                zeno_max_buyers: u128::default(),
+                zeno_curve: u8::default()
             }
         }
     }
@@ -402,11 +612,19 @@ mod geode_marketplace {
         total_carts: u128,
         total_orders: u128,
         total_delivered: u128,
+        total_complete: u128,
         total_damaged: u128,
         total_wrong: u128,
         total_not_received: u128,
+        total_not_as_described: u128,
+        total_counterfeit: u128,
+        total_missing_parts: u128,
+        total_service_not_rendered: u128,
+        total_broken_file: u128,
         total_resolved: u128,
         total_refused: u128,
+        total_arbitrations: u128,
+        arbitrations_lost: u128,
     }
 
     impl Default for BuyerProfile {
@@ -421,11 +639,19 @@ mod geode_marketplace {
                 total_carts: u128::default(),
                 total_orders: u128::default(),
                 total_delivered: u128::default(),
+                total_complete: u128::default(),
                 total_damaged: u128::default(),
                 total_wrong: u128::default(),
                 total_not_received: u128::default(),
+                total_not_as_described: u128::default(),
+                total_counterfeit: u128::default(),
+                total_missing_parts: u128::default(),
+                total_service_not_rendered: u128::default(),
+                total_broken_file: u128::default(),
                 total_resolved: u128::default(),
                 total_refused: u128::default(),
+                total_arbitrations: u128::default(),
+                arbitrations_lost: u128::default(),
             }
         }
     }
@@ -447,12 +673,30 @@ mod geode_marketplace {
         total_orders: u128,
         awaiting: u128,
         total_delivered: u128,
+        total_complete: u128,
         total_damaged: u128,
         total_wrong: u128,
         total_not_received: u128,
+        total_not_as_described: u128,
+        total_counterfeit: u128,
+        total_missing_parts: u128,
+        total_service_not_rendered: u128,
+        total_broken_file: u128,
         total_resolved: u128,
         total_refused: u128,
+        total_arbitrations: u128,
+        arbitrations_lost: u128,
+        total_missed_handling: u128,
+        cancel_window: u64,
+        dispute_window: u64,
+        bond: Balance,
+        bond_backed: bool,
+        bond_withdrawal_requested: u64,
     }
+    // cancel_window: how long (ms) after ordering a buyer may cancel an awaiting order, 0 = until shipped
+    // dispute_window: how long (ms) buyers have to report a problem on this seller's orders, 0 = 24 hours
+    // bond: the seller's security deposit, bond_backed is the badge shown while a bond is staked
+    // and no withdrawal is pending, bond_withdrawal_requested starts the 14 day withdrawal cooldown
 
     impl Default for SellerProfile {
         fn default() -> SellerProfile {
@@ -470,11 +714,25 @@ mod geode_marketplace {
                 total_orders: u128::default(),
                 awaiting: u128::default(),
                 total_delivered: u128::default(),
+                total_complete: u128::default(),
                 total_damaged: u128::default(),
                 total_wrong: u128::default(),
                 total_not_received: u128::default(),
+                total_not_as_described: u128::default(),
+                total_counterfeit: u128::default(),
+                total_missing_parts: u128::default(),
+                total_service_not_rendered: u128::default(),
+                total_broken_file: u128::default(),
                 total_resolved: u128::default(),
                 total_refused: u128::default(),
+                total_arbitrations: u128::default(),
+                arbitrations_lost: u128::default(),
+                total_missed_handling: u128::default(),
+                cancel_window: u64::default(),
+                dispute_window: u64::default(),
+                bond: Balance::default(),
+                bond_backed: false,
+                bond_withdrawal_requested: u64::default(),
             }
         }
     }
@@ -663,9 +921,9 @@ mod geode_marketplace {
     pub struct OrderData {
         timestamp: u64,
         total: Balance,
-        status: u8,
-        problem: u8,
-        resolution: u8,
+        status: OrderStatus,
+        problem: ProblemKind,
+        resolution: Resolution,
     }
 
     #[derive(Clone, Debug, PartialEq, Eq)]
@@ -697,6 +955,230 @@ mod geode_marketplace {
         }
     }
 
+    // where a buyer protection claim stands
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum ClaimStatus {
+        #[default]
+        Pending,
+        Approved,
+        Rejected,
+    }
+
+    // a buyer's claim on the protection pool for a denied problem order
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct ProtectionClaim {
+        order_id: Hash,
+        buyer: AccountId,
+        amount_requested: Balance,
+        amount_paid: Balance,
+        status: ClaimStatus,
+        time_filed: u64,
+        time_decided: u64,
+    }
+
+    impl Default for ProtectionClaim {
+        fn default() -> ProtectionClaim {
+            ProtectionClaim {
+                order_id: Hash::default(),
+                buyer: AccountId::from([0x0; 32]),
+                amount_requested: Balance::default(),
+                amount_paid: Balance::default(),
+                status: ClaimStatus::Pending,
+                time_filed: u64::default(),
+                time_decided: u64::default(),
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct ProtectionPool {
+        balance: Balance,
+        protection_bps: u128,
+        claim_cap_per_order: Balance,
+        claim_cap_per_buyer: Balance,
+        total_funded: Balance,
+        total_paid: Balance,
+        claims: Vec<ProtectionClaim>,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct PlatformFees {
+        treasury: AccountId,
+        platform_fee_bps: u128,
+        total_accrued: Balance,
+        total_withdrawn: Balance,
+        available: Balance,
+        daily_fees: Vec<(u64, Balance)>
+        // (day number since the unix epoch, fees accrued that day)
+    }
+
+    impl Default for PlatformFees {
+        fn default() -> PlatformFees {
+            PlatformFees {
+                treasury: AccountId::from([0x0; 32]),
+                platform_fee_bps: 0,
+                total_accrued: Balance::default(),
+                total_withdrawn: Balance::default(),
+                available: Balance::default(),
+                daily_fees: <Vec<(u64, Balance)>>::default()
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct ZenoProjection {
+        item_id: Hash,
+        price: Balance,
+        zeno_percent: u128,
+        zeno_max_buyers: u128,
+        zeno_curve: u8,
+        zeno_unique_buyers: bool,
+        filled_slots: u128,
+        slot_earnings: Vec<Balance>
+        // what each zeno slot earns from every future sale of one unit at the current price
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct ZenoEarning {
+        item_id: Hash,
+        title: Vec<u8>,
+        slots: Vec<u128>,
+        // the caller's positions in the item's zeno buyers list, starting at 1
+        earned: Balance,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct ViewZenoEarnings {
+        affiliate: AccountId,
+        lifetime_total: Balance,
+        items: Vec<ZenoEarning>
+    }
+
+    impl Default for ViewZenoEarnings {
+        fn default() -> ViewZenoEarnings {
+            ViewZenoEarnings {
+                affiliate: AccountId::from([0x0; 32]),
+                lifetime_total: Balance::default(),
+                items: <Vec<ZenoEarning>>::default()
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct DroppedCartItem {
+        item_id: Hash,
+        quantity: u128,
+        reason: u8,
+    }
+    // reason code: 1 = not enough inventory, 2 = item no longer listed
+
+    #[derive(Clone, Debug, PartialEq, Eq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct CheckoutReceipt {
+        cart_id: Hash,
+        order_ids: Vec<Hash>,
+        dropped_items: Vec<DroppedCartItem>,
+        amount_charged: Balance,
+        change_refunded: Balance,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct SellerSubtotal {
+        seller: AccountId,
+        subtotal: Balance,
+    }
+
+    impl Default for SellerSubtotal {
+        fn default() -> SellerSubtotal {
+            SellerSubtotal {
+                seller: AccountId::from([0x0; 32]),
+                subtotal: Balance::default(),
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct PriceChange {
+        item_id: Hash,
+        price_when_added: Balance,
+        current_price: Balance,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct CheckoutQuote {
+        buyer: AccountId,
+        amount_due: Balance,
+        seller_subtotals: Vec<SellerSubtotal>,
+        dropped_items: Vec<DroppedCartItem>,
+        price_changes: Vec<PriceChange>,
+    }
+
+    impl Default for CheckoutQuote {
+        fn default() -> CheckoutQuote {
+            CheckoutQuote {
+                buyer: AccountId::from([0x0; 32]),
+                amount_due: Balance::default(),
+                seller_subtotals: <Vec<SellerSubtotal>>::default(),
+                dropped_items: <Vec<DroppedCartItem>>::default(),
+                price_changes: <Vec<PriceChange>>::default(),
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct EscrowedOrder {
+        order_id: Hash,
+        amount: Balance,
+        order_status: OrderStatus,
+        time_shipped: u64,
+        release_time: u64,
+        // release_time is 0 until the order ships
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct ViewEscrow {
+        seller: AccountId,
+        total_escrowed: Balance,
+        orders: Vec<EscrowedOrder>
+    }
+
+    impl Default for ViewEscrow {
+        fn default() -> ViewEscrow {
+            ViewEscrow {
+                seller: AccountId::from([0x0; 32]),
+                total_escrowed: Balance::default(),
+                orders: <Vec<EscrowedOrder>>::default()
+            }
+        }
+    }
+
     
     // EVENT DEFINITIONS >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>> 
 
@@ -746,7 +1228,7 @@ mod geode_marketplace {
         buyer: AccountId,
         #[ink(topic)]
         seller: AccountId,
-        problem: u8,
+        problem: ProblemKind,
     }
 
     // buyer has updted their account settings
@@ -784,7 +1266,7 @@ mod geode_marketplace {
         buyer: AccountId,
         #[ink(topic)]
         order_id: Hash,
-        order_status: u8,
+        order_status: OrderStatus,
         time_delivered: u64,
     }
 
@@ -797,7 +1279,7 @@ mod geode_marketplace {
         buyer: AccountId,
         #[ink(topic)]
         order_id: Hash,
-        order_status: u8,
+        order_status: OrderStatus,
     }
 
     // seller refused an order
@@ -809,3169 +1291,6658 @@ mod geode_marketplace {
         buyer: AccountId,
         #[ink(topic)]
         order_id: Hash,
-        order_status: u8,
+        order_status: OrderStatus,
     }
 
-    // seller issued a refund
+    // buyer cancelled an order before it shipped
     #[ink(event)]
-    pub struct OrderRefunded {
+    pub struct OrderCancelled {
         #[ink(topic)]
         seller: AccountId,
         #[ink(topic)]
         buyer: AccountId,
         #[ink(topic)]
         order_id: Hash,
-        order_status: u8,
-        problem: u8,
-        resolution: u8,
+        order_status: OrderStatus,
+        refund: Balance,
     }
 
-    // seller issued a replacement
+    // the inventory on a listing changed because of an order
+    // reason code: 1 = sold, 2 = order refused, 3 = order cancelled, 4 = returned after a refund
     #[ink(event)]
-    pub struct OrderReplaced {
+    pub struct InventoryAdjusted {
+        #[ink(topic)]
+        item_id: Hash,
+        #[ink(topic)]
+        seller: AccountId,
+        order_id: Hash,
+        quantity: u128,
+        inventory: u128,
+        reason: u8,
+    }
+
+    // seller missed the handling deadline and the order was refunded
+    #[ink(event)]
+    pub struct OrderHandlingExpired {
         #[ink(topic)]
         seller: AccountId,
         #[ink(topic)]
         buyer: AccountId,
         #[ink(topic)]
         order_id: Hash,
-        order_status: u8,
-        problem: u8,
-        resolution: u8,
+        order_status: OrderStatus,
+        refund: Balance,
+        called_by: AccountId,
     }
 
-    // seller denied resolution request
+    // seller authorized the buyer to send a damaged or wrong item back
     #[ink(event)]
-    pub struct OrderResolutionDenied {
+    pub struct ReturnAuthorized {
         #[ink(topic)]
         seller: AccountId,
         #[ink(topic)]
         buyer: AccountId,
         #[ink(topic)]
         order_id: Hash,
-        order_status: u8,
-        problem: u8,
-        resolution: u8,
+        order_status: OrderStatus,
+        return_address: Vec<u8>,
+        refund: Balance,
     }
 
-    // new rating for a buyer and details
+    // buyer sent the item back
     #[ink(event)]
-    pub struct NewBuyerRating {
+    pub struct ReturnShipped {
         #[ink(topic)]
-        review_id: Hash,
+        seller: AccountId,
         #[ink(topic)]
         buyer: AccountId,
         #[ink(topic)]
-        reviewer: AccountId,
-        rating: u64,
-        review: Vec<u8>,
-        timestamp: u64,
+        order_id: Hash,
+        order_status: OrderStatus,
+        return_tracking: Vec<u8>,
     }
 
-    // new product added
+    // the returned item arrived and the buyer was refunded
     #[ink(event)]
-    pub struct NewProduct {
+    pub struct ReturnReceived {
         #[ink(topic)]
-        product_id: Hash,
-        digital: bool,
+        seller: AccountId,
         #[ink(topic)]
-        title: Vec<u8>,
-        price: Balance,
-        brand: Vec<u8>,
-        category: Vec<u8>,
+        buyer: AccountId,
         #[ink(topic)]
-        seller_account: AccountId,
-        seller_name: Vec<u8>,
-        description: Vec<u8>, 
-        inventory: u128, 
-        photo_or_youtube_link1: Vec<u8>, 
-        photo_or_youtube_link2: Vec<u8>,
-        photo_or_youtube_link3: Vec<u8>,
-        more_info_link: Vec<u8>,
-        delivery_info: Vec<u8>,
-        product_location: Vec<u8>,
-        zeno_percent: u128,
+        order_id: Hash,
+        refund: Balance,
+        confirmed_by: AccountId,
     }
 
-    // product details updated
+    // seller withdrew a return authorization the buyer never acted on
     #[ink(event)]
-    pub struct UpdatedProduct {
+    pub struct ReturnAuthorizationCancelled {
         #[ink(topic)]
-        product_id: Hash,
-        digital: bool,
+        seller: AccountId,
         #[ink(topic)]
-        title: Vec<u8>,
-        price: Balance,
-        brand: Vec<u8>,
-        category: Vec<u8>,
+        buyer: AccountId,
         #[ink(topic)]
-        seller_account: AccountId,
-        seller_name: Vec<u8>,
-        description: Vec<u8>, 
-        inventory: u128, 
-        photo_or_youtube_link1: Vec<u8>, 
-        photo_or_youtube_link2: Vec<u8>,
-        photo_or_youtube_link3: Vec<u8>,
-        more_info_link: Vec<u8>,
-        delivery_info: Vec<u8>,
-        product_location: Vec<u8>,
+        order_id: Hash,
+        order_status: OrderStatus,
     }
 
-    // new service added
+    // seller scheduled a service order
     #[ink(event)]
-    pub struct NewService {
+    pub struct ServiceScheduled {
         #[ink(topic)]
-        service_id: Hash,
-        online: bool,
+        seller: AccountId,
         #[ink(topic)]
-        title: Vec<u8>,
-        price: Balance,
-        category: Vec<u8>,
+        buyer: AccountId,
         #[ink(topic)]
-        seller_account: AccountId,
-        seller_name: Vec<u8>,
-        description: Vec<u8>,
-        inventory: u128,
-        photo_or_youtube_link1: Vec<u8>, 
-        photo_or_youtube_link2: Vec<u8>,
-        photo_or_youtube_link3: Vec<u8>,
-        booking_link: Vec<u8>,
-        service_location: Vec<u8>,
-        zeno_percent: u128,
+        order_id: Hash,
+        service_date: u64,
     }
 
-    // service details updated
+    // seller marked a service order as rendered, which starts the problem window
     #[ink(event)]
-    pub struct UpdatedService {
+    pub struct ServiceCompleted {
         #[ink(topic)]
-        service_id: Hash,
-        online: bool,
+        seller: AccountId,
         #[ink(topic)]
-        title: Vec<u8>,
-        price: Balance,
-        category: Vec<u8>,
+        buyer: AccountId,
         #[ink(topic)]
-        seller_account: AccountId,
-        seller_name: Vec<u8>,
-        description: Vec<u8>,
-        inventory: u128,
-        photo_or_youtube_link1: Vec<u8>, 
-        photo_or_youtube_link2: Vec<u8>,
-        photo_or_youtube_link3: Vec<u8>,
-        booking_link: Vec<u8>,
-        service_location: Vec<u8>,
+        order_id: Hash,
+        time_service_completed: u64,
     }
 
+    // the admin paused or unpaused the marketplace
+    #[ink(event)]
+    pub struct PauseUpdated {
+        #[ink(topic)]
+        admin: AccountId,
+        paused_all: bool,
+        paused_checkout: bool,
+    }
 
+    // the admin proposed a new admin account
+    #[ink(event)]
+    pub struct AdminProposed {
+        #[ink(topic)]
+        admin: AccountId,
+        #[ink(topic)]
+        proposed_admin: AccountId,
+    }
 
-    // ERROR DEFINITIONS >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+    // the proposed admin accepted and took over
+    #[ink(event)]
+    pub struct AdminTransferred {
+        #[ink(topic)]
+        previous_admin: AccountId,
+        #[ink(topic)]
+        new_admin: AccountId,
+    }
 
-    #[derive(Debug, PartialEq, Eq)]
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    pub enum Error {
-        // if the payment fails
-        PayoutFailed,
-        // naming an account that was not there
-        NonexistentAccount,
-        // naming an item that does not exist
-        ItemDoesNotExist,
-        // adding an item or seller to a list twice
-        Duplicate,
-        // not paying enough for your cart 
-        InsufficientPayment,
-        // trying to review an item or seller you didn't buy / buy from
-        NotEligibleToReview,
-        // rating number outside of 1-5
-        RatingOutOfBounds,
-        // reporting a problem on an order you did not place
-        NotYourOrder,
-        // reporting a problem too late
-        NotEligibleToReport,
-        // editing a list you do not own
-        NotYourList,
-        // refusing an order that has already shipped or delivered
-        CannotRefuse,
-        // updating tracking on the wrong kind of item
-        NotAPhysicalProduct,
-        // trying to resolve an order that is not in problem status
-        CannotResolve,
-        // updating a product you do not own
-        NotYourProduct,
-        // input data is too large for storage
-        DataTooLarge,
-        // storage for that item is full, please delete some
-        StorageFull,
+    // the admin changed the buyer protection pool settings
+    #[ink(event)]
+    pub struct ProtectionSettingsUpdated {
+        #[ink(topic)]
+        admin: AccountId,
+        protection_bps: u128,
+        claim_cap_per_order: Balance,
+        claim_cap_per_buyer: Balance,
     }
 
+    // buyer filed a claim on the buyer protection pool
+    #[ink(event)]
+    pub struct ProtectionClaimFiled {
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        order_id: Hash,
+        amount_requested: Balance,
+    }
 
-    // ACTUAL CONTRACT STORAGE STRUCT >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+    // the admin paid or rejected a protection claim
+    #[ink(event)]
+    pub struct ProtectionClaimDecided {
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        order_id: Hash,
+        approved: bool,
+        amount_paid: Balance,
+        decided_by: AccountId,
+    }
 
-    #[ink(storage)]
-    pub struct ContractStorage {
-        all_sellers: StorageVec<AccountId>,
-        total_count_buyers: u128,
-        total_count_services: u128,
-        total_count_products: u128,
-        total_count_orders: u128,
-        all_orders: Vec<Hash>,
-        account_profile_seller: Mapping<AccountId, SellerProfile>,
-        account_profile_buyer: Mapping<AccountId, BuyerProfile>,
-        account_store_bookmarks: Mapping<AccountId, AccountVector>,
-        account_buyer_orders: Mapping<AccountId, HashVector>,
-        account_buyer_items_bought: Mapping<AccountId, HashVector>,
-        account_buyer_items_reviewed: Mapping<AccountId, HashVector>,
-        account_seller_buyers_reviewed: Mapping<AccountId, AccountVector>,
-        account_owned_digital_items: Mapping<AccountId, HashVector>,
-        account_seller_orders_0awaiting: Mapping<AccountId, HashVector>,
-        account_seller_orders_1shipped: Mapping<AccountId, HashVector>,
-        account_seller_orders_2delivered: Mapping<AccountId, HashVector>,
-        account_seller_orders_3resolved: Mapping<AccountId, HashVector>,
-        account_seller_orders_4problem: Mapping<AccountId, HashVector>,
-        account_seller_orders_5refused: Mapping<AccountId, HashVector>,
-        account_current_cart: Mapping<AccountId, UnpaidCart>,
-        account_seller_products: Mapping<AccountId, HashVector>,
-        account_seller_services: Mapping<AccountId, HashVector>,
-        product_details: Mapping<Hash, Product>,
-        service_details: Mapping<Hash, Service>,
-        order_details: Mapping<Hash, Order>,
+    // seller added to their security deposit
+    #[ink(event)]
+    pub struct BondDeposited {
+        #[ink(topic)]
+        seller: AccountId,
+        amount: Balance,
+        bond: Balance,
     }
 
+    // seller started the cooldown to take their security deposit back
+    #[ink(event)]
+    pub struct BondWithdrawalRequested {
+        #[ink(topic)]
+        seller: AccountId,
+        bond: Balance,
+        available_after: u64,
+    }
 
-    // BEGIN CONTRACT LOGIC >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+    // seller's security deposit went back to their claimable balance
+    #[ink(event)]
+    pub struct BondWithdrawn {
+        #[ink(topic)]
+        seller: AccountId,
+        amount: Balance,
+    }
 
-    impl ContractStorage {
-        
-        // CONSTRUCTORS >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-        // Constructors are implicitly payable.
+    // part of a seller's security deposit was taken to pay a buyer
+    #[ink(event)]
+    pub struct BondSlashed {
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        order_id: Hash,
+        amount: Balance,
+        bond_remaining: Balance,
+    }
 
-        #[ink(constructor)]
-        pub fn new() -> Self {
-            Self {
-                all_sellers: StorageVec::default(),
-                total_count_buyers: 0,
-                total_count_services: 0,
-                total_count_products: 0,
-                total_count_orders: 0,
-                all_orders: <Vec<Hash>>::default(),
-                account_profile_seller: Mapping::default(),
-                account_profile_buyer: Mapping::default(),
-                account_store_bookmarks: Mapping::default(),
-                account_buyer_orders: Mapping::default(),
-                account_buyer_items_bought: Mapping::default(),
-                account_buyer_items_reviewed: Mapping::default(),
-                account_seller_buyers_reviewed: Mapping::default(),
-                account_owned_digital_items: Mapping::default(),
-                account_seller_orders_0awaiting: Mapping::default(),
-                account_seller_orders_1shipped: Mapping::default(),
-                account_seller_orders_2delivered: Mapping::default(),
-                account_seller_orders_3resolved: Mapping::default(),
-                account_seller_orders_4problem: Mapping::default(),
-                account_seller_orders_5refused: Mapping::default(),
-                account_current_cart: Mapping::default(),
-                account_seller_products: Mapping::default(),
-                account_seller_services: Mapping::default(),
-                product_details: Mapping::default(),
-                service_details: Mapping::default(),
-                order_details: Mapping::default(),
-            }
-        }
+    // the admin changed how much of a bond is taken when a seller abandons an order
+    #[ink(event)]
+    pub struct AbandonPenaltyUpdated {
+        #[ink(topic)]
+        admin: AccountId,
+        abandon_penalty_bps: u128,
+    }
 
+    // buyer escalated a denied problem to arbitration
+    #[ink(event)]
+    pub struct DisputeEscalated {
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        order_id: Hash,
+        timestamp: u64,
+    }
 
-        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-        // MESSAGE FUNCTIONS THAT CHANGE DATA IN THE CONTRACT STORAGE >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+    // an arbiter ruled on an escalated dispute
+    #[ink(event)]
+    pub struct ArbitrationRuling {
+        #[ink(topic)]
+        order_id: Hash,
+        #[ink(topic)]
+        arbiter: AccountId,
+        seller: AccountId,
+        buyer: AccountId,
+        ruling: Arbitration,
+        refund: Balance,
+        from_escrow: Balance,
+        from_bond: Balance,
+        from_seller_balance: Balance,
+    }
 
+    // the admin added or removed an arbiter
+    #[ink(event)]
+    pub struct ArbiterUpdated {
+        #[ink(topic)]
+        admin: AccountId,
+        #[ink(topic)]
+        arbiter: AccountId,
+        added: bool,
+    }
 
-        // 0 🟢 Add Item To Cart
-        #[ink(message)]
-        pub fn add_item_to_cart (&mut self, 
-            add_item_id: Hash, 
-            quantity: u128
-        ) -> Result<(), Error> {
-            // set up the caller
-            let caller = Self::env().caller();
-            // get the current unpaid cart for this caller from account_current_cart
-            let mut cart = self.account_current_cart.get(caller).unwrap_or_default();
-            // if the cart is full, send an error
-            if cart.cart_items.len() > 49 {
-                return Err(Error::StorageFull);
-            }
-            // if the cart is empty, add this item
-            if cart.total_items == 0 {
-                // push the item and quantity onto the vector of cart_items
-                cart.cart_items.push((add_item_id, quantity));
-            }
-            // if the cart is not empty...
-            else {
-                let mut new_quantity = quantity;
-                let mut old_quantity: u128 = 0;
-                // is this item already in the cart?
-                for (item, number) in &cart.cart_items {
-                    if *item == add_item_id {
-                        // increase the quantity
-                        old_quantity = *number;
-                        new_quantity = number.saturating_add(quantity);
-                    }
-                }
-                // remove the old entry
-                cart.cart_items.retain(|value| *value != (add_item_id,old_quantity));
-                // add this item
-                cart.cart_items.push((add_item_id, new_quantity));
-            }
-            // get the total number of items in the cart
-            let totalitems: u128 = cart.cart_items.len().try_into().unwrap();
-            // iterate through the cart items to get the total price of the cart
-            let mut carttotal: Balance = 0;
-            for (item, number) in &cart.cart_items {
-                // get the price for that item
-                let mut item_price: Balance = 0;
-                if self.product_details.contains(item) {
-                    item_price = self.product_details.get(item).unwrap_or_default().price;
-                }
-                else {
-                    if self.service_details.contains(item) {
-                        item_price = self.service_details.get(item).unwrap_or_default().price;
-                    }
-                }
-                // add the price to the total price
-                carttotal = carttotal.saturating_add(item_price.saturating_mul(*number));
-            }
+    // seller changed how long buyers have to report a problem
+    // item_id is the default hash when the store-wide policy changed
+    #[ink(event)]
+    pub struct DisputeWindowUpdated {
+        #[ink(topic)]
+        seller_account: AccountId,
+        #[ink(topic)]
+        item_id: Hash,
+        dispute_window: u64,
+    }
 
-            // perpare the updated UnpaidCart
-            let updated_cart = UnpaidCart {
-                buyer: caller,
-                cart_total: carttotal,
-                total_items: totalitems,
-                cart_items: cart.cart_items
-            };
+    // seller changed how long buyers have to cancel
+    #[ink(event)]
+    pub struct CancellationWindowUpdated {
+        #[ink(topic)]
+        seller_account: AccountId,
+        cancel_window: u64,
+    }
 
-            // update mappings
-            self.account_current_cart.insert(caller, &updated_cart);
-            
-            Ok(())
-        }
+    // seller issued a refund
+    #[ink(event)]
+    pub struct OrderRefunded {
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        order_id: Hash,
+        order_status: OrderStatus,
+        problem: ProblemKind,
+        resolution: Resolution,
+        refunded_amount: Balance,
+    }
 
+    // seller offered a partial refund
+    #[ink(event)]
+    pub struct RefundOffered {
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        order_id: Hash,
+        amount: Balance,
+        total_order_price: Balance,
+    }
 
-        // 1 🟢 Bookmark A Store
-        #[ink(message)]
-        pub fn bookmark_a_store (&mut self, 
+    // buyer declined a partial refund offer
+    #[ink(event)]
+    pub struct RefundOfferDeclined {
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        order_id: Hash,
+        amount: Balance,
+    }
+
+    // seller issued a replacement
+    #[ink(event)]
+    pub struct OrderReplaced {
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        order_id: Hash,
+        order_status: OrderStatus,
+        problem: ProblemKind,
+        resolution: Resolution,
+    }
+
+    // seller denied resolution request
+    #[ink(event)]
+    pub struct OrderResolutionDenied {
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        order_id: Hash,
+        order_status: OrderStatus,
+        problem: ProblemKind,
+        resolution: Resolution,
+    }
+
+    // new rating for a buyer and details
+    #[ink(event)]
+    pub struct NewBuyerRating {
+        #[ink(topic)]
+        review_id: Hash,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        reviewer: AccountId,
+        rating: u64,
+        review: Vec<u8>,
+        timestamp: u64,
+    }
+
+    // new product added
+    #[ink(event)]
+    pub struct NewProduct {
+        #[ink(topic)]
+        product_id: Hash,
+        digital: bool,
+        #[ink(topic)]
+        title: Vec<u8>,
+        price: Balance,
+        brand: Vec<u8>,
+        category: Vec<u8>,
+        #[ink(topic)]
+        seller_account: AccountId,
+        seller_name: Vec<u8>,
+        description: Vec<u8>, 
+        inventory: u128, 
+        photo_or_youtube_link1: Vec<u8>, 
+        photo_or_youtube_link2: Vec<u8>,
+        photo_or_youtube_link3: Vec<u8>,
+        more_info_link: Vec<u8>,
+        delivery_info: Vec<u8>,
+        product_location: Vec<u8>,
+        zeno_percent: u128,
+        handling_time: u64,
+    }
+
+    // product details updated
+    #[ink(event)]
+    pub struct UpdatedProduct {
+        #[ink(topic)]
+        product_id: Hash,
+        digital: bool,
+        #[ink(topic)]
+        title: Vec<u8>,
+        price: Balance,
+        brand: Vec<u8>,
+        category: Vec<u8>,
+        #[ink(topic)]
+        seller_account: AccountId,
+        seller_name: Vec<u8>,
+        description: Vec<u8>, 
+        inventory: u128, 
+        photo_or_youtube_link1: Vec<u8>, 
+        photo_or_youtube_link2: Vec<u8>,
+        photo_or_youtube_link3: Vec<u8>,
+        more_info_link: Vec<u8>,
+        delivery_info: Vec<u8>,
+        product_location: Vec<u8>,
+        handling_time: u64,
+    }
+
+    // new service added
+    #[ink(event)]
+    pub struct NewService {
+        #[ink(topic)]
+        service_id: Hash,
+        online: bool,
+        #[ink(topic)]
+        title: Vec<u8>,
+        price: Balance,
+        category: Vec<u8>,
+        #[ink(topic)]
+        seller_account: AccountId,
+        seller_name: Vec<u8>,
+        description: Vec<u8>,
+        inventory: u128,
+        photo_or_youtube_link1: Vec<u8>, 
+        photo_or_youtube_link2: Vec<u8>,
+        photo_or_youtube_link3: Vec<u8>,
+        booking_link: Vec<u8>,
+        service_location: Vec<u8>,
+        zeno_percent: u128,
+    }
+
+    // service details updated
+    #[ink(event)]
+    pub struct UpdatedService {
+        #[ink(topic)]
+        service_id: Hash,
+        online: bool,
+        #[ink(topic)]
+        title: Vec<u8>,
+        price: Balance,
+        category: Vec<u8>,
+        #[ink(topic)]
+        seller_account: AccountId,
+        seller_name: Vec<u8>,
+        description: Vec<u8>,
+        inventory: u128,
+        photo_or_youtube_link1: Vec<u8>, 
+        photo_or_youtube_link2: Vec<u8>,
+        photo_or_youtube_link3: Vec<u8>,
+        booking_link: Vec<u8>,
+        service_location: Vec<u8>,
+    }
+
+    // order confirmed by the buyer or finalized after the problem window closed
+    #[ink(event)]
+    pub struct OrderCompleted {
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        order_id: Hash,
+        order_status: OrderStatus,
+        timestamp: u64,
+    }
+
+    // admin changed the platform fee or treasury account
+    #[ink(event)]
+    pub struct PlatformFeeUpdated {
+        #[ink(topic)]
+        admin: AccountId,
+        #[ink(topic)]
+        treasury: AccountId,
+        platform_fee_bps: u128,
+    }
+
+    // treasury withdrew accrued platform fees
+    #[ink(event)]
+    pub struct PlatformFeesWithdrawn {
+        #[ink(topic)]
+        treasury: AccountId,
+        amount: Balance,
+        timestamp: u64,
+    }
+
+    // payment, zeno earnings or refund credited to an account's claimable balance
+    #[ink(event)]
+    pub struct BalanceCredited {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        order_id: Hash,
+        amount: Balance,
+        reason: CreditReason,
+    }
+
+    // account withdrew its claimable balance
+    #[ink(event)]
+    pub struct BalanceWithdrawn {
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+        timestamp: u64,
+    }
+
+    // zeno affiliate paid their slot's share of a sale
+    #[ink(event)]
+    pub struct ZenoPayout {
+        #[ink(topic)]
+        affiliate: AccountId,
+        #[ink(topic)]
+        item_id: Hash,
+        #[ink(topic)]
+        order_id: Hash,
+        slot: u128,
+        amount: Balance,
+    }
+
+    // seller changed the zeno incentive settings on a listing
+    #[ink(event)]
+    pub struct ZenoSettingsUpdated {
+        #[ink(topic)]
+        item_id: Hash,
+        #[ink(topic)]
+        seller_account: AccountId,
+        zeno_max_buyers: u128,
+        zeno_curve: u8,
+        zeno_unique_buyers: bool,
+    }
+
+    // escrowed payment released to the seller
+    #[ink(event)]
+    pub struct EscrowReleased {
+        #[ink(topic)]
+        order_id: Hash,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        released_by: AccountId,
+        amount: Balance,
+        timestamp: u64,
+    }
+
+
+
+    // ERROR DEFINITIONS >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+    #[derive(Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum Error {
+        // if the payment fails
+        PayoutFailed,
+        // naming an account that was not there
+        NonexistentAccount,
+        // naming an item that does not exist
+        ItemDoesNotExist,
+        // adding an item or seller to a list twice
+        Duplicate,
+        // not paying enough for your cart 
+        InsufficientPayment,
+        // trying to review an item or seller you didn't buy / buy from
+        NotEligibleToReview,
+        // rating number outside of 1-5
+        RatingOutOfBounds,
+        // reporting a problem on an order you did not place
+        NotYourOrder,
+        // reporting a problem too late
+        NotEligibleToReport,
+        // editing a list you do not own
+        NotYourList,
+        // refusing an order that has already shipped or delivered
+        CannotRefuse,
+        // updating tracking on the wrong kind of item
+        NotAPhysicalProduct,
+        // trying to resolve an order that is not in problem status
+        CannotResolve,
+        // updating a product you do not own
+        NotYourProduct,
+        // input data is too large for storage
+        DataTooLarge,
+        // storage for that item is full, please delete some
+        StorageFull,
+        // releasing escrow on an order that has nothing held
+        NothingInEscrow,
+        // releasing escrow before the buyer confirms or the timeout expires
+        EscrowLocked,
+        // completing an order that has not shipped or is already closed
+        CannotComplete,
+        // an item in the cart costs more than the buyer agreed to pay
+        PriceChanged,
+        // calling an admin message from a non-admin account
+        NotAdmin,
+        // withdrawing platform fees from an account that is not the treasury
+        NotTreasury,
+        // setting a fee or penalty above 10000 basis points (100%), the platform fee and
+        // protection slice count together
+        FeeTooHigh,
+        // withdrawing when there is no claimable balance
+        NothingToWithdraw,
+        // zeno settings outside the allowed range, or changed after zeno slots were filled
+        InvalidZenoSettings,
+        // refunding nothing or more than the buyer paid
+        InvalidRefundAmount,
+        // accepting or declining a refund offer that does not exist
+        NoRefundOffer,
+        // order already shipped or the seller's cancellation window has passed
+        CannotCancel,
+        // only refunded orders can be restocked, and only once
+        CannotRestock,
+        // the seller still has time to ship this order
+        HandlingTimeNotExpired,
+        // caller is not a registered arbiter
+        NotArbiter,
+        // only denied problems can be escalated, once, within 24 hours of the denial
+        CannotEscalate,
+        // the order is not waiting on an arbiter
+        NotInArbitration,
+        // the order is not at the right step of the return process
+        CannotReturn,
+        // the return is still in progress
+        ReturnNotExpired,
+        // the order cannot move from its current status to the one requested
+        IllegalTransition,
+        // reporting no problem, or a problem that does not apply to this kind of order
+        InvalidProblemKind,
+        // scheduling or completing an order that is not an open service order
+        NotAService,
+        // dispute windows must be between 1 hour and 30 days (or 0 for the default)
+        InvalidDisputeWindow,
+        // bond withdrawal was not requested or the cooldown has not passed
+        BondCooldown,
+        // the seller still has orders that are not finished
+        OpenOrders,
+        // only denied problem orders can claim on the pool, once, within the caps
+        CannotClaim,
+        // no pending claim for this order
+        NoPendingClaim,
+        // the admin has paused the marketplace (or just checkout)
+        Paused,
+        // the caller is not the proposed admin
+        NotPendingAdmin,
+        // a service cannot be scheduled for a date that has already passed
+        InvalidServiceDate,
+        // the pool is empty or the buyer has reached their cap, so the claim cannot be paid yet
+        ClaimNotPayable,
+    }
+
+
+    // ACTUAL CONTRACT STORAGE STRUCT >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+    #[ink(storage)]
+    pub struct ContractStorage {
+        all_sellers: StorageVec<AccountId>,
+        total_count_buyers: u128,
+        total_count_services: u128,
+        total_count_products: u128,
+        total_count_orders: u128,
+        all_orders: Vec<Hash>,
+        account_profile_seller: Mapping<AccountId, SellerProfile>,
+        account_profile_buyer: Mapping<AccountId, BuyerProfile>,
+        account_store_bookmarks: Mapping<AccountId, AccountVector>,
+        account_buyer_orders: Mapping<AccountId, HashVector>,
+        account_buyer_items_bought: Mapping<AccountId, HashVector>,
+        account_buyer_items_reviewed: Mapping<AccountId, HashVector>,
+        account_seller_buyers_reviewed: Mapping<AccountId, AccountVector>,
+        account_owned_digital_items: Mapping<AccountId, HashVector>,
+        account_seller_orders_0awaiting: Mapping<AccountId, HashVector>,
+        account_seller_orders_1shipped: Mapping<AccountId, HashVector>,
+        account_seller_orders_2delivered: Mapping<AccountId, HashVector>,
+        account_seller_orders_3resolved: Mapping<AccountId, HashVector>,
+        account_seller_orders_4problem: Mapping<AccountId, HashVector>,
+        account_seller_orders_5refused: Mapping<AccountId, HashVector>,
+        account_current_cart: Mapping<AccountId, UnpaidCart>,
+        account_seller_products: Mapping<AccountId, HashVector>,
+        account_seller_services: Mapping<AccountId, HashVector>,
+        product_details: Mapping<Hash, Product>,
+        service_details: Mapping<Hash, Service>,
+        order_details: Mapping<Hash, Order>,
+        escrow_timeout: u64,
+        delivery_window: u64,
+        order_escrow: Mapping<Hash, Balance>,
+        account_seller_escrow: Mapping<AccountId, HashVector>,
+        admin: AccountId,
+        treasury: AccountId,
+        platform_fee_bps: u128,
+        fees_available: Balance,
+        fees_accrued_total: Balance,
+        fees_withdrawn_total: Balance,
+        fee_days: Vec<u64>,
+        fees_by_day: Mapping<u64, Balance>,
+        claimable_balance: Mapping<AccountId, Balance>,
+        account_zeno_items: Mapping<AccountId, HashVector>,
+        account_zeno_item_earnings: Mapping<(AccountId, Hash), Balance>,
+        account_zeno_earnings_total: Mapping<AccountId, Balance>,
+        arbiters: Vec<AccountId>,
+        open_disputes: Vec<Hash>,
+        protection_bps: u128,
+        claim_cap_per_order: Balance,
+        claim_cap_per_buyer: Balance,
+        protection_pool: Balance,
+        protection_funded_total: Balance,
+        protection_paid_total: Balance,
+        protection_claims: Mapping<Hash, ProtectionClaim>,
+        protection_claim_history: Vec<Hash>,
+        account_protection_paid: Mapping<AccountId, Balance>,
+        pending_admin: Option<AccountId>,
+        paused_all: bool,
+        paused_checkout: bool,
+        abandon_penalty_bps: u128,
+    }
+
+
+    // BEGIN CONTRACT LOGIC >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+    impl ContractStorage {
+        
+        // CONSTRUCTORS >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // Constructors are implicitly payable.
+
+        // escrow_timeout is the time in milliseconds after an order ships
+        // when anyone can release the escrowed payment to the seller
+        // delivery_window is the time in milliseconds a seller has after shipping to mark an order
+        // delivered, after that the buyer can report it not received or anyone can mark it delivered
+        // platform_fee_bps is the marketplace fee in basis points (100 = 1%) taken from seller payouts,
+        // deploying with a fee over 100% fails
+        // the account that deploys the contract becomes the admin
+        #[ink(constructor)]
+        pub fn new(escrow_timeout: u64, delivery_window: u64, platform_fee_bps: u128, treasury: AccountId) -> Result<Self, Error> {
+            // no protection slice is set yet, so the fee alone cannot pass 100%
+            if platform_fee_bps > 10000 {
+                return Err(Error::FeeTooHigh);
+            }
+            Ok(Self {
+                all_sellers: StorageVec::default(),
+                total_count_buyers: 0,
+                total_count_services: 0,
+                total_count_products: 0,
+                total_count_orders: 0,
+                all_orders: <Vec<Hash>>::default(),
+                account_profile_seller: Mapping::default(),
+                account_profile_buyer: Mapping::default(),
+                account_store_bookmarks: Mapping::default(),
+                account_buyer_orders: Mapping::default(),
+                account_buyer_items_bought: Mapping::default(),
+                account_buyer_items_reviewed: Mapping::default(),
+                account_seller_buyers_reviewed: Mapping::default(),
+                account_owned_digital_items: Mapping::default(),
+                account_seller_orders_0awaiting: Mapping::default(),
+                account_seller_orders_1shipped: Mapping::default(),
+                account_seller_orders_2delivered: Mapping::default(),
+                account_seller_orders_3resolved: Mapping::default(),
+                account_seller_orders_4problem: Mapping::default(),
+                account_seller_orders_5refused: Mapping::default(),
+                account_current_cart: Mapping::default(),
+                account_seller_products: Mapping::default(),
+                account_seller_services: Mapping::default(),
+                product_details: Mapping::default(),
+                service_details: Mapping::default(),
+                order_details: Mapping::default(),
+                escrow_timeout,
+                delivery_window,
+                order_escrow: Mapping::default(),
+                account_seller_escrow: Mapping::default(),
+                admin: Self::env().caller(),
+                treasury,
+                platform_fee_bps,
+                fees_available: 0,
+                fees_accrued_total: 0,
+                fees_withdrawn_total: 0,
+                fee_days: <Vec<u64>>::default(),
+                fees_by_day: Mapping::default(),
+                claimable_balance: Mapping::default(),
+                account_zeno_items: Mapping::default(),
+                account_zeno_item_earnings: Mapping::default(),
+                account_zeno_earnings_total: Mapping::default(),
+                arbiters: <Vec<AccountId>>::default(),
+                open_disputes: <Vec<Hash>>::default(),
+                protection_bps: 0,
+                claim_cap_per_order: 0,
+                claim_cap_per_buyer: 0,
+                protection_pool: 0,
+                protection_funded_total: 0,
+                protection_paid_total: 0,
+                protection_claims: Mapping::default(),
+                protection_claim_history: <Vec<Hash>>::default(),
+                account_protection_paid: Mapping::default(),
+                pending_admin: None,
+                paused_all: false,
+                paused_checkout: false,
+                abandon_penalty_bps: 0,
+            })
+        }
+
+
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // MESSAGE FUNCTIONS THAT CHANGE DATA IN THE CONTRACT STORAGE >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+
+        // 0 🟢 Add Item To Cart
+        #[ink(message)]
+        pub fn add_item_to_cart (&mut self, 
+            add_item_id: Hash, 
+            quantity: u128
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // set up the caller
+            let caller = Self::env().caller();
+            // get the current unpaid cart for this caller from account_current_cart
+            let mut cart = self.account_current_cart.get(caller).unwrap_or_default();
+            // if the cart is full, send an error
+            if cart.cart_items.len() > 49 {
+                return Err(Error::StorageFull);
+            }
+            // if the cart is empty, add this item
+            if cart.total_items == 0 {
+                // push the item and quantity onto the vector of cart_items
+                cart.cart_items.push((add_item_id, quantity));
+            }
+            // if the cart is not empty...
+            else {
+                let mut new_quantity = quantity;
+                let mut old_quantity: u128 = 0;
+                // is this item already in the cart?
+                for (item, number) in &cart.cart_items {
+                    if *item == add_item_id {
+                        // increase the quantity
+                        old_quantity = *number;
+                        new_quantity = number.saturating_add(quantity);
+                    }
+                }
+                // remove the old entry
+                cart.cart_items.retain(|value| *value != (add_item_id,old_quantity));
+                // add this item
+                cart.cart_items.push((add_item_id, new_quantity));
+            }
+            // record the unit price the buyer sees for this item right now
+            let price_now = self.listing_details(add_item_id).map(|(_, price, _)| price).unwrap_or_default();
+            cart.cart_prices.retain(|(item, _)| *item != add_item_id);
+            cart.cart_prices.push((add_item_id, price_now));
+            // get the total number of items in the cart
+            let totalitems: u128 = cart.cart_items.len().try_into().unwrap();
+            // iterate through the cart items to get the total price of the cart
+            let mut carttotal: Balance = 0;
+            for (item, number) in &cart.cart_items {
+                // get the price for that item
+                let mut item_price: Balance = 0;
+                if self.product_details.contains(item) {
+                    item_price = self.product_details.get(item).unwrap_or_default().price;
+                }
+                else {
+                    if self.service_details.contains(item) {
+                        item_price = self.service_details.get(item).unwrap_or_default().price;
+                    }
+                }
+                // add the price to the total price
+                carttotal = carttotal.saturating_add(item_price.saturating_mul(*number));
+            }
+
+            // perpare the updated UnpaidCart
+            let updated_cart = UnpaidCart {
+                buyer: caller,
+                cart_total: carttotal,
+                total_items: totalitems,
+                cart_items: cart.cart_items,
+                cart_prices: cart.cart_prices
+            };
+
+            // update mappings
+            self.account_current_cart.insert(caller, &updated_cart);
+            
+            Ok(())
+        }
+
+
+        // 1 🟢 Bookmark A Store
+        #[ink(message)]
+        pub fn bookmark_a_store (&mut self, 
+            seller: AccountId,
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // set up clones
+            // set up the caller
+            let caller = Self::env().caller();
+            // get the account_store_boookmarks list
+            let mut my_list = self.account_store_bookmarks.get(caller).unwrap_or_default();
+            // if their bookmarks are full or the seller is already on the list, send an error
+            if my_list.accountvector.len() > 19 || my_list.accountvector.contains(&seller) {
+                return Err(Error::StorageFull);
+            }
+            else {
+                // if the seller is not there already, add them to the vector
+                my_list.accountvector.push(seller);
+                // update mapping account_store_bookmarks: Mapping<AccountId, AccountVector>
+                self.account_store_bookmarks.insert(caller, &my_list);
+
+                Ok(())
+            }
+        }
+
+        // 2 🟢 Remove A Store Bookmark
+        #[ink(message)]
+        pub fn remove_store_bookmark (&mut self, 
             seller: AccountId,
         ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // set up the caller
+            let caller = Self::env().caller();
+            // get the account_store_boookmarks list
+            let mut my_list = self.account_store_bookmarks.get(caller).unwrap_or_default();
+            if my_list.accountvector.contains(&seller) {
+                // remove the store account from the bookmark list
+                my_list.accountvector.retain(|value| *value != seller);
+                // update mapping account_store_bookmarks: Mapping<AccountId, AccountVector>
+                self.account_store_bookmarks.insert(caller, &my_list);
+            }
+            
+            Ok(())
+        }
+
+        
+        // 3 🟢 Remove Item From Cart
+        #[ink(message)]
+        pub fn remove_item_from_cart (&mut self, 
+            item_id: Hash,
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // set up the caller
+            let caller = Self::env().caller();
+            // get the caller's current unpaid cart id
+            let mut cart = self.account_current_cart.get(caller).unwrap_or_default();
+            // get the quantity for that item in the cart
+            let mut quantity: u128 = 0;
+            for (item, number) in &cart.cart_items {
+                if *item == item_id {
+                    quantity = *number;
+                }
+            }
+            // remove item from cart_items vector
+            cart.cart_items.retain(|value| *value != (item_id, quantity));
+            cart.cart_prices.retain(|(item, _)| *item != item_id);
+
+            // get the total number of items in the cart
+            let totalitems: u128 = cart.cart_items.len().try_into().unwrap();
+            // iterate through the cart items to get the total price of the cart
+            let mut carttotal: Balance = 0;
+            for (item, number) in &cart.cart_items {
+                // get the price for that item
+                let mut item_price: Balance = 0;
+                if self.product_details.contains(item) {
+                    item_price = self.product_details.get(item).unwrap_or_default().price;
+                }
+                else {
+                    if self.service_details.contains(item) {
+                        item_price = self.service_details.get(item).unwrap_or_default().price;
+                    }
+                }
+                // add the price to the total price
+                carttotal = carttotal.saturating_add(item_price.saturating_mul(*number));
+            }
+ 
+            // perpare the updated UnpaidCart
+            let updated_cart = UnpaidCart {
+                buyer: caller,
+                cart_total: carttotal,
+                total_items: totalitems,
+                cart_items: cart.cart_items,
+                cart_prices: cart.cart_prices
+            };
+ 
+            // update mappings
+            self.account_current_cart.insert(caller, &updated_cart);           
+            
+            Ok(())
+        }
+        
+    
+        // 4 🟢 Update Cart Item Quantity
+        #[ink(message)]
+        pub fn update_cart_item_quantity (&mut self, 
+            item_id: Hash,
+            new_quantity: u128
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // set up the caller
+            let caller = Self::env().caller();
+            // get the caller's current unpaid cart id
+            let mut cart = self.account_current_cart.get(caller).unwrap_or_default();
+            // get the quantity for that item in the cart
+            let mut quantity: u128 = 0;
+            for (item, number) in &cart.cart_items {
+                if *item == item_id {
+                    quantity = *number;
+                }
+            }
+            // remove item and old quantity from the cart_items vector
+            cart.cart_items.retain(|value| *value != (item_id, quantity));
+            // add the item with the new quantity to the cart_items vector
+            cart.cart_items.push((item_id, new_quantity));
+            // if this item is new to the cart, record the unit price the buyer sees right now
+            if !cart.cart_prices.iter().any(|(item, _)| *item == item_id) {
+                let price_now = self.listing_details(item_id).map(|(_, price, _)| price).unwrap_or_default();
+                cart.cart_prices.push((item_id, price_now));
+            }
+
+            // get the total number of items in the cart
+            let totalitems: u128 = cart.cart_items.len().try_into().unwrap();
+            // iterate through the cart items to get the total price of the cart
+            let mut carttotal: Balance = 0;
+            for (item, number) in &cart.cart_items {
+                // get the price for that item
+                let mut item_price: Balance = 0;
+                if self.product_details.contains(item) {
+                    item_price = self.product_details.get(item).unwrap_or_default().price;
+                }
+                else {
+                    if self.service_details.contains(item) {
+                        item_price = self.service_details.get(item).unwrap_or_default().price;
+                    }
+                }
+                // add the price to the total price
+                carttotal = carttotal.saturating_add(item_price.saturating_mul(*number));
+            }
+ 
+            // perpare the updated UnpaidCart
+            let updated_cart = UnpaidCart {
+                buyer: caller,
+                cart_total: carttotal,
+                total_items: totalitems,
+                cart_items: cart.cart_items,
+                cart_prices: cart.cart_prices
+            };
+ 
+            // update mappings
+            self.account_current_cart.insert(caller, &updated_cart);   
+            
+            Ok(())
+        }
+
+        
+        // 5 🟢 Checkout Cart
+        // max_cart_total caps what the buyer is willing to be charged for the whole cart
+        // max_price_increase_percent caps how far any item's price may rise since it was added
+        // if neither is given, no item may cost more than it did when it was added to the cart
+        #[ink(message, payable)]
+        pub fn checkout_cart (&mut self, 
+            deliver_to_address: Vec<u8>,
+            max_cart_total: Option<Balance>,
+            max_price_increase_percent: Option<u128>
+        ) -> Result<CheckoutReceipt, Error> {
+            // checkout can be paused on its own or along with everything else
+            self.check_not_paused(true)?;
+
+            // make sure the address is not too long
+            if deliver_to_address.len() > 300 {
+                return Err(Error::DataTooLarge);
+            }
+
+            // set up the caller and timestamp
+            let caller = Self::env().caller();
+            let rightnow = self.env().block_timestamp();
+
+            // get the caller's unpaid cart
+            let current_cart = self.account_current_cart.get(caller).unwrap_or_default();
+
+            // UPDATE THE CART TOTAL AND REMOVE ITEMS THAT DO NOT HAVE ENOUGH INVENTORY
+            let (final_cart_items, dropped_items, carttotal) = self.sort_cart_items(&current_cart);
+
+            // PROTECT THE BUYER FROM PRICE INCREASES SINCE ITEMS WERE ADDED
+            if let Some(max_total) = max_cart_total {
+                if carttotal > max_total {
+                    return Err(Error::PriceChanged);
+                }
+            }
+            if max_price_increase_percent.is_some() || max_cart_total.is_none() {
+                let tolerance = max_price_increase_percent.unwrap_or_default();
+                for (item, _) in final_cart_items.iter() {
+                    let price_now = self.listing_details(*item).map(|(_, price, _)| price).unwrap_or_default();
+                    if let Some((_, price_when_added)) = current_cart.cart_prices.iter().find(|(id, _)| id == item) {
+                        let price_limit = price_when_added.saturating_mul(tolerance.saturating_add(100)).saturating_div(100);
+                        if price_now > price_limit {
+                            return Err(Error::PriceChanged);
+                        }
+                    }
+                }
+            }
+            
+            // COLLECT PAYMENT FROM THE CALLER
+            // the 'payable' tag on this message allows the user to send any amount
+            let amount_paid: Balance = self.env().transferred_value();
+            if amount_paid < carttotal {
+                // error, did not pay enough
+                return Err(Error::InsufficientPayment);
+            }
+            else {
+                // make the cart_id hash
+                let encodable = (caller, rightnow); // Implements `scale::Encode`
+                let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
+                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
+                let new_cart_id: Hash = Hash::from(new_id_u8);
+
+                // set up the vector of orders and items that will be referenced later
+                let mut all_cart_orders = <Vec<Order>>::default();
+                let mut all_cart_items = <Vec<Hash>>::default();
+                let mut total_items_count = u128::default();
+                let mut total_orders_count = u128::default();
+
+                // FOR EACH ITEM IN THE CART ...
+                for (item, number) in &final_cart_items {
+
+                    // set up clones
+                    let deliver_to_address_clone1 = deliver_to_address.clone();
+                    
+                    // CREATE THE ORDER STRUCT FOR THIS ITEM...
+
+                    let mut item_seller: AccountId = AccountId::from([0x0; 32]);
+                    let mut item_seller_name: Vec<u8> = <Vec<u8>>::default();
+                    let mut item_image:Vec<u8> = <Vec<u8>>::default();
+                    let mut item_name: Vec<u8> = <Vec<u8>>::default();
+                    let mut item_price: Balance = 0;
+                    let mut item_zeno_percent: u128 = 0;
+                    let mut item_is_digital: bool = false;
+                    let mut item_is_service: bool = false;
+                    let item_inventory: u128;
+                    let mut item_handling_time: u64 = 0;
+                    let item_dispute_window: u64;
+                    let item_zeno_max_buyers: u128;
+                    let item_zeno_curve: u8;
+
+                    // get the details for this item
+                    if self.product_details.contains(item) {
+                        let mut details = self.product_details.get(item).unwrap_or_default();
+                        item_seller = details.seller_account;
+                        item_seller_name = details.seller_name.clone();
+                        item_image = details.photo_or_youtube_link1.clone();
+                        item_name = details.title.clone();
+                        item_price = details.price;
+                        item_zeno_percent = details.zeno_percent;
+                        item_zeno_max_buyers = details.zeno_max_buyers;
+                        item_zeno_curve = details.zeno_curve;
+                        item_is_digital = details.digital; 
+
+                        // update zeno buyers information for the item...
+                        // if there are open zeno slots (and this is not a repeat buyer when
+                        // the seller only counts unique buyers), add this buyer to the list
+                        let zeno_count: u128 = details.zeno_buyers.len().try_into().unwrap();
+                        if zeno_count < details.zeno_max_buyers
+                        && !(details.zeno_unique_buyers && details.zeno_buyers.contains(&caller)) {
+                            details.zeno_buyers.push(caller);
+                            self.record_zeno_item(caller, *item);
+                        }
+
+                        // reduce the inventory on this item by the quantity bought
+                        details.inventory = details.inventory.saturating_sub(*number);
+                        item_inventory = details.inventory;
+                        item_handling_time = details.handling_time;
+                        item_dispute_window = details.dispute_window;
+
+                        // update the product details map
+                        self.product_details.insert(item, &details);
+
+                    }
+                    else {
+                        if self.service_details.contains(item) {
+                            item_is_service = true;
+                            let mut details = self.service_details.get(item).unwrap_or_default();
+                            item_seller = details.seller_account;
+                            item_seller_name = details.seller_name.clone();
+                            item_image = details.photo_or_youtube_link1.clone();
+                            item_name = details.title.clone();
+                            item_price = details.price;
+                            item_zeno_percent = details.zeno_percent;
+                            item_zeno_max_buyers = details.zeno_max_buyers;
+                            item_zeno_curve = details.zeno_curve;
+
+                            // update zeno information for the item...
+                            // if there are open zeno slots (and this is not a repeat buyer when
+                            // the seller only counts unique buyers), add this buyer to the list
+                            let zeno_count: u128 = details.zeno_buyers.len().try_into().unwrap();
+                            if zeno_count < details.zeno_max_buyers
+                            && !(details.zeno_unique_buyers && details.zeno_buyers.contains(&caller)) {
+                                details.zeno_buyers.push(caller);
+                                self.record_zeno_item(caller, *item);
+                            }
+
+                            // reduce the inventory on this item by the quantity bought
+                            details.inventory = details.inventory.saturating_sub(*number);
+                            item_inventory = details.inventory;
+                            item_dispute_window = details.dispute_window;
+
+                            // update the service details map
+                            self.service_details.insert(item, &details);
+
+                        }
+                        else {
+                            // error, item does not exist
+                            return Err(Error::ItemDoesNotExist)
+                        }
+                    }
+
+                    // make the order_id hash, total_count_orders keeps two checkouts
+                    // of the same item in the same block from getting the same id
+                    let encodable = (caller, rightnow, item, self.total_count_orders); // Implements `scale::Encode`
+                    let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
+                    ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
+                    let new_order_id: Hash = Hash::from(new_id_u8);
+                    
+                    // calculate the order total
+                    let item_order_total: Balance = number.saturating_mul(item_price);
+
+                    // calculate the zeno total
+                    let item_zeno_total: Balance = item_order_total.saturating_mul(item_zeno_percent).saturating_div(100); 
+
+                    // calculate the platform fee, taken from the seller's payout
+                    let item_platform_fee: Balance = item_order_total.saturating_mul(self.platform_fee_bps).saturating_div(10000);
+
+                    // calculate the buyer protection slice, also taken from the seller's payout
+                    let item_protection_fee: Balance = item_order_total.saturating_mul(self.protection_bps).saturating_div(10000);
+
+                    // account for alternate order status when the product is digital
+                    let mut status = OrderStatus::Awaiting;
+                    if item_is_digital || item_is_service {
+                        status = OrderStatus::Delivered;
+                    }
+
+                    // the problem window comes from the listing, then the seller's policy, then 24 hours
+                    let mut item_dispute_window = item_dispute_window;
+                    if item_dispute_window == 0 {
+                        let seller_profile = self.account_profile_seller.get(item_seller).unwrap_or_default();
+                        item_dispute_window = seller_profile.dispute_window;
+                    }
+                    if item_dispute_window == 0 {
+                        item_dispute_window = 86400000;
+                    }
+
+                    // physical orders must ship within the product's handling time (DEFAULT_HANDLING_TIME if not set)
+                    let mut handling_deadline: u64 = 0;
+                    if status == OrderStatus::Awaiting {
+                        if item_handling_time == 0 {
+                            item_handling_time = DEFAULT_HANDLING_TIME;
+                        }
+                        handling_deadline = rightnow.saturating_add(item_handling_time);
+                    }
+
+                    // get the buyer profile
+                    let mut buyer_profile = self.account_profile_buyer.get(caller).unwrap_or_default();
+
+                    // set up the Order structure
+                    let mut new_order = Order {
+                        order_id: new_order_id,
+                        cart_id: new_cart_id,
+                        order_timestamp: rightnow,
+                        buyer: caller,
+                        buyer_rating: buyer_profile.review_average,
+                        buyer_rating_count: buyer_profile.review_count,
+                        seller: item_seller,
+                        seller_name: item_seller_name,
+                        image: item_image,
+                        item_id: *item,
+                        item_name: item_name,
+                        quantity: *number,
+                        price_each: item_price,
+                        total_order_price: item_order_total,
+                        deliver_to_address: deliver_to_address_clone1,
+                        deliver_to_account: caller,
+                        tracking_info: <Vec<u8>>::default(),
+                        order_status: status, 
+                        time_shipped: u64::default(),
+                        time_delivered: u64::default(),
+                        discussion: <Vec<MessageDetails>>::default(),
+                        problem: ProblemKind::None,
+                        resolution: Resolution::None,
+                        zeno_total: item_zeno_total,
+                        platform_fee: item_platform_fee,
+                        refunded_amount: Balance::default(),
+                        refund_offered: Balance::default(),
+                        restocked: false,
+                        handling_deadline: handling_deadline,
+                        delivery_deadline: u64::default(),
+                        time_denied: u64::default(),
+                        arbitration: Arbitration::None,
+                        arbiter: AccountId::from([0x0; 32]),
+                        return_address: <Vec<u8>>::default(),
+                        return_tracking: <Vec<u8>>::default(),
+                        return_refund_held: Balance::default(),
+                        time_return_authorized: u64::default(),
+                        time_return_shipped: u64::default(),
+                        time_return_received: u64::default(),
+                        timeline: <Vec<StatusChange>>::default(),
+                        is_digital: item_is_digital,
+                        is_service: item_is_service,
+                        service_date: u64::default(),
+                        time_service_completed: u64::default(),
+                        dispute_window: item_dispute_window,
+                        protection_fee: item_protection_fee,
+                        zeno_max_buyers: item_zeno_max_buyers,
+                        zeno_curve: item_zeno_curve
+                    };
+                    // the first timeline entry records the order being placed
+                    self.add_timeline_entry(&mut new_order, caller, b"order placed");
+
+                    // SPECIAL ACTIONS FOR DIGITAL PRODUCTS...
+                    // if the item is a digital product, send ownership to the buyer and pay the seller
+                    if item_is_digital {
+                        // get this account's set of owned digital items
+                        let mut owned = self.account_owned_digital_items.get(caller).unwrap_or_default();
+                        // is this item already in the owned list?
+                        if owned.hashvector.contains(item) {
+                            // do nothing
+                        }
+                        else {
+                            // if the owner already has 400 digital dowloads in storage, remove the oldest
+                            if owned.hashvector.len() > 399 {
+                                owned.hashvector.remove(0);
+                            }
+                            owned.hashvector.push(*item);
+                            // update account_owned_digital_items: Mapping<AccountId, HashVector>
+                            self.account_owned_digital_items.insert(caller, &owned);
+                        }
+                        
+                        // mark the order as delivered
+                        new_order.time_delivered = rightnow;
+                        
+                        // credit the seller and zeno buyers for the digital product, less the platform fee
+                        self.pay_out_order(&new_order, item_order_total);
+                    }
+
+                    // PAYOUT SERVICES
+                    if item_is_service {
+                        // mark the order as delivered
+                        new_order.time_delivered = rightnow;
+                        
+                        // credit the seller and zeno buyers for the service, less the platform fee
+                        self.pay_out_order(&new_order, item_order_total);
+                    }
+
+                    // update order_details: Mapping<Hash, Order>
+                    self.order_details.insert(new_order_id, &new_order);
+
+                    // EMIT EVENT InventoryAdjusted for the items sold
+                    Self::env().emit_event(InventoryAdjusted {
+                        item_id: *item,
+                        seller: item_seller,
+                        order_id: new_order_id,
+                        quantity: *number,
+                        inventory: item_inventory,
+                        reason: 1,
+                    });
+
+                    // update all_orders: Vec<Hash> keep the 490 most recent
+                    if self.all_orders.len() > 489 {
+                        // kick out the oldest
+                        self.all_orders.remove(0);
+                    }
+                    self.all_orders.push(new_order_id);
+                    // increase total_count_orders by one
+                    self.total_count_orders = self.total_count_orders.saturating_add(1);
+
+                    // update all_cart_orders, all_cart_items, total_items_count, and total_orders_count
+                    all_cart_orders.push(new_order);
+                    all_cart_items.push(*item);
+                    total_items_count = total_items_count.saturating_add(*number);
+                    total_orders_count = total_orders_count.saturating_add(1);
+                                        
+                    // update account_buyer_orders: Mapping<AccountId, HashVector>
+                    let mut buyer_orders = self.account_buyer_orders.get(caller).unwrap_or_default();
+                    // if there are more than 24 orders here, remove the oldest
+                    if buyer_orders.hashvector.len() > 24 {
+                        buyer_orders.hashvector.remove(0);
+                    }
+                    buyer_orders.hashvector.push(new_order_id);
+                    self.account_buyer_orders.insert(caller, &buyer_orders);
+
+                    // update account_buyer_items_bought: Mapping<AccountId, HashVector>
+                    let mut buyer_items = self.account_buyer_items_bought.get(caller).unwrap_or_default();
+                    if buyer_items.hashvector.contains(item) {
+                        // do nothing
+                    }
+                    else {
+                        // if there are more than 399 items here, remove the oldest
+                        if buyer_items.hashvector.len() > 399 {
+                            buyer_items.hashvector.remove(0);
+                        }
+                        buyer_items.hashvector.push(*item);
+                        self.account_buyer_items_bought.insert(caller, &buyer_items);
+                    }
+
+                    // update account_seller_orders: Mapping<AccountId, HashVector> based on status (0 or 2)
+                    if status == OrderStatus::Awaiting {
+                        let mut seller_orders = self.account_seller_orders_0awaiting.get(item_seller).unwrap_or_default();
+                        // if the seller_orders.hashvector AWAITING is full, send error
+                        if seller_orders.hashvector.len() > 69 && status == OrderStatus::Awaiting {
+                            return Err(Error::StorageFull);
+                        }
+                        // otherwise, add this order and update the mapping
+                        seller_orders.hashvector.push(new_order_id);
+                        self.account_seller_orders_0awaiting.insert(item_seller, &seller_orders);
+
+                        // hold the payment for this physical product in escrow
+                        // entries leave the seller's escrow list once their escrow is paid out or refunded
+                        let mut seller_escrow = self.account_seller_escrow.get(item_seller).unwrap_or_default();
+                        seller_escrow.hashvector.push(new_order_id);
+                        self.account_seller_escrow.insert(item_seller, &seller_escrow);
+                        // order_escrow: Mapping<Hash, Balance>
+                        self.order_escrow.insert(new_order_id, &item_order_total);
+                    }
+                    if status == OrderStatus::Delivered {
+                        let mut seller_orders = self.account_seller_orders_2delivered.get(item_seller).unwrap_or_default();
+                        // if the seller_orders.hashvector DELIVERED full, remove the oldest
+                        if seller_orders.hashvector.len() > 69 && status == OrderStatus::Delivered {
+                            // remove the oldest
+                            seller_orders.hashvector.remove(0);
+                        }
+                        // add this order and update the mapping
+                        seller_orders.hashvector.push(new_order_id);
+                        self.account_seller_orders_2delivered.insert(item_seller, &seller_orders);
+                    }
+                    
+                    // update account_profile_seller: Mapping<AccountId, SellerProfile>
+                    let mut seller_profile = self.account_profile_seller.get(item_seller).unwrap_or_default();
+                    // increment total_orders
+                    seller_profile.total_orders = seller_profile.total_orders.saturating_add(1);
+                    if item_is_digital || item_is_service {
+                        seller_profile.total_delivered = seller_profile.total_delivered.saturating_add(1);
+                    }
+                    // update the total awaiting orders
+                    let awaiting_now = self.account_seller_orders_0awaiting.get(item_seller).unwrap_or_default().hashvector.len();
+                    seller_profile.awaiting = awaiting_now.try_into().unwrap();
+                    // update the map 
+                    self.account_profile_seller.insert(item_seller, &seller_profile);
+
+                    // update account_profile_buyer: Mapping<AccountId, BuyerProfile>
+                    // increment total_orders
+                    buyer_profile.total_orders = buyer_profile.total_orders.saturating_add(1);
+                    if item_is_digital || item_is_service {
+                        buyer_profile.total_delivered = buyer_profile.total_delivered.saturating_add(1);
+                    }
+                    // if this is the first order, set the member_since timestamp
+                    // and add one to the total_count_buyers
+                    if buyer_profile.member_since == u64::default() {
+                        buyer_profile.member_since = rightnow;
+                        self.total_count_buyers = self.total_count_buyers.saturating_add(1);
+                    }
+                    self.account_profile_buyer.insert(caller, &buyer_profile);
+
+                    // EMIT EVENT to register the order to the chain
+                    Self::env().emit_event(OrderPlaced {
+                        order_id: new_order_id,
+                        order_timestamp: rightnow,
+                        buyer: caller,
+                        seller: item_seller,
+                        total_order_price: item_order_total,
+                    });
+
+                    // EMIT EVENT For DIGITAL DOWNLOAD OWNERSHIP
+                    if item_is_digital {
+                        Self::env().emit_event(DigitalDownload {
+                            buyer: caller,
+                            product_id: *item,
+                            order_timestamp: rightnow,
+                        });
+                    }
+                }
+
+                // UPDATE CART RELATED STORAGE MAPPINGS...
+
+                // update account_profile_buyer: Mapping<AccountId, BuyerProfile>
+                let mut buyer_profile = self.account_profile_buyer.get(caller).unwrap_or_default();
+                // increment total_carts and total_orders
+                buyer_profile.total_carts = buyer_profile.total_carts.saturating_add(1);
+                self.account_profile_buyer.insert(caller, &buyer_profile);
+
+                // delete caller's unpaid cart in account_current_cart: Mapping<AccountId, UnpaidCart>
+                self.account_current_cart.remove(caller);
+
+                // REFUND ANY OVERPAYMENT TO THE CALLER
+                let change: Balance = amount_paid.saturating_sub(carttotal);
+                if change > 0 && self.env().transfer(caller, change).is_err() {
+                    return Err(Error::PayoutFailed);
+                }
+
+                // package the receipt
+                let receipt = CheckoutReceipt {
+                    cart_id: new_cart_id,
+                    order_ids: all_cart_orders.iter().map(|order| order.order_id).collect(),
+                    dropped_items,
+                    amount_charged: carttotal,
+                    change_refunded: change,
+                };
+
+                Ok(receipt)
+            }
+        }
+
+        
+        // 6 🟢 Rate A Product or Service
+        #[ink(message)]
+        pub fn rate_a_product_or_service (&mut self, 
+            item_id: Hash,
+            rating: u64,
+            review: Vec<u8>
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // if the X if full, send an error
+            if review.len() > 600 {
+                return Err(Error::DataTooLarge);
+            }
+            // if the rating is between 1 and 5
+            if rating > 0 && rating < 6 {
+                // set up the caller
+                let caller = Self::env().caller();
+                let now = self.env().block_timestamp();
+
+                // account_buyer_items_bought: Mapping<AccountId, HashVector>
+                let bought = self.account_buyer_items_bought.get(caller).unwrap_or_default();
+                // account_buyer_items_reviewed: Mapping<AccountId, HashVector>
+                let mut reviewed = self.account_buyer_items_reviewed.get(caller).unwrap_or_default();
+                if bought.hashvector.contains(&item_id) {
+                    // did you already review it?, if so, error
+                    if reviewed.hashvector.contains(&item_id) {
+                        return Err(Error::NotEligibleToReview)
+                    }
+                    else {
+                        // make the review_id hash
+                        let encodable = (caller, item_id); // Implements `scale::Encode`
+                        let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
+                        ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
+                        let new_review_id: Hash = Hash::from(new_id_u8);
+                        
+                        // update mappings...
+                        // account_buyer_items_reviewed: Mapping<AccountId, HashVector>
+                        // if the reviewed hashvector if full, send an error
+                        if reviewed.hashvector.len() > 399 {
+                            // remove the oldest
+                            reviewed.hashvector.remove(0);
+                        }
+                        reviewed.hashvector.push(item_id);
+                        self.account_buyer_items_reviewed.insert(caller, &reviewed);
+                        
+                        if self.product_details.contains(item_id) {
+                            // update product_details: Mapping<Hash, Product>
+                            let mut details = self.product_details.get(item_id).unwrap_or_default();
+                            let oldsum = details.review_count.saturating_mul(details.review_average);
+                            let newsum = oldsum.saturating_add(rating);
+                            // recalculate the review count
+                            details.review_count = details.review_count.saturating_add(1);
+                            // recalcualte the review average
+                            details.review_average = newsum.div_euclid(details.review_count);
+                            // return to storage
+                            self.product_details.insert(item_id, &details);
+
+                            // add this review to the list of all reviews for this seller on their profile
+                            // instead of seller ratings, just use the aggregate of all product and service ratings
+                            // get the seller
+                            let seller = details.seller_account;
+                            // get the seller profile
+                            // account_profile_seller: Mapping<AccountId, SellerProfile>
+                            let mut profile = self.account_profile_seller.get(seller).unwrap_or_default();
+                            let oldsum = profile.review_count.saturating_mul(profile.review_average);
+                            let newsum = oldsum.saturating_add(rating);
+                            // recalculate the review count
+                            profile.review_count = profile.review_count.saturating_add(1);
+                            // recalcualte the review average
+                            profile.review_average = newsum.div_euclid(profile.review_count);
+                            // return to storage
+                            self.account_profile_seller.insert(seller, &profile);
+
+                        }
+                        else {
+                            if self.service_details.contains(item_id) {
+                                // update service_details: Mapping<Hash, Service>
+                                let mut details = self.service_details.get(item_id).unwrap_or_default();
+                                let oldsum = details.review_count.saturating_mul(details.review_average);
+                                let newsum = oldsum.saturating_add(rating);
+                                // recalculate the review count
+                                details.review_count = details.review_count.saturating_add(1);
+                                // recalcualte the review average
+                                details.review_average = newsum.div_euclid(details.review_count);
+                                // return to storage
+                                self.service_details.insert(item_id, &details);
+
+                                // add this review to the list of all reviews for this seller on their profile
+                                // instead of seller ratings, just use the aggregate of all product and service ratings
+                                // get the seller
+                                let seller = details.seller_account;
+                                // get the seller profile
+                                // account_profile_seller: Mapping<AccountId, SellerProfile>
+                                let mut profile = self.account_profile_seller.get(seller).unwrap_or_default();
+                                let oldsum = profile.review_count.saturating_mul(profile.review_average);
+                                let newsum = oldsum.saturating_add(rating);
+                                // recalculate the review count
+                                profile.review_count = profile.review_count.saturating_add(1);
+                                // recalcualte the review average
+                                profile.review_average = newsum.div_euclid(profile.review_count);
+                                // return to storage
+                                self.account_profile_seller.insert(seller, &profile);
+
+                            }
+                            else {
+                                return Err(Error::ItemDoesNotExist)
+                            }
+                        }
+
+                        // EMIT EVENT NewProductRating
+                        Self::env().emit_event(NewProductRating {
+                            review_id: new_review_id,
+                            item_id: item_id,
+                            reviewer: caller,
+                            rating: rating,
+                            review: review,
+                            timestamp: now,
+                        });
+
+                    }
+                }
+                else {
+                    // if you did not buy it, error
+                    return Err(Error::NotEligibleToReview)
+                }
+            }
+            else {
+                return Err(Error::RatingOutOfBounds)
+            }
+            Ok(())
+        }
+
+
+        // 7 🟢 Report Problem
+        // one message for every kind of problem, the kind decides when it can be reported
+        // and which profile counters go up
+        #[ink(message)]
+        pub fn report_problem (&mut self, 
+            order_id: Hash,
+            kind: ProblemKind,
+            evidence: Vec<u8>,
+            message: Vec<u8>,
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // if the inputs are too big, send an error
+            if evidence.len() > 200 || message.len() > 200 {
+                return Err(Error::DataTooLarge);
+            }
+            if kind == ProblemKind::None {
+                return Err(Error::InvalidProblemKind);
+            }
+            // set up clones
+            let message_clone = message.clone();
+            // set up the caller
+            let caller = Self::env().caller();
+            // is this your order? account_buyer_orders: Mapping<AccountId, HashVector>
+            let myorders = self.account_buyer_orders.get(caller).unwrap_or_default();
+            if myorders.hashvector.contains(&order_id) {
+                // get the order details order_details: Mapping<Hash, Order>
+                let mut details = self.order_details.get(order_id).unwrap_or_default();
+                let seller = details.seller;
+                // a service that was never rendered is only a problem for service orders
+                // and a broken file is only a problem for digital products
+                if (kind == ProblemKind::ServiceNotRendered && !details.is_service)
+                || (kind == ProblemKind::BrokenFile && !details.is_digital) {
+                    return Err(Error::InvalidProblemKind);
+                }
+                // Check the status. To report a problem, the status must be delivered
+                // and the order's dispute window (see problem_window_start) must still be open,
+                // or (for not received) the order must still be shipped after its delivery deadline
+                // and within the dispute window that follows it, the same window that locks the escrow
+                let now = self.env().block_timestamp();
+                let window_open = now < self.problem_window_start(&details).saturating_add(details.dispute_window);
+                let was_shipped = details.order_status == OrderStatus::Shipped;
+                let overdue = kind == ProblemKind::NotReceived && was_shipped
+                && details.delivery_deadline > 0 && now > details.delivery_deadline
+                && now < details.delivery_deadline.saturating_add(details.dispute_window);
+                if (window_open && details.order_status == OrderStatus::Delivered) || overdue {
+                    // make the message_id hash
+                    let encodable = (caller, now, order_id, message); // Implements `scale::Encode`
+                    let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
+                    ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
+                    let new_message_id: Hash = Hash::from(new_id_u8);
+                    
+                    // update message_details: Mapping<Hash, MessageDetails>
+                    let message_details = MessageDetails {
+                        message_id: new_message_id,
+                        from_acct: caller,
+                        to_acct: seller,
+                        order_id: order_id,
+                        message: message_clone,
+                        media_url: evidence,
+                        timestamp: now
+                    };
+
+                    // update order details
+                    self.transition(&mut details, OrderStatus::Problem, caller, b"problem reported")?;
+                    details.problem = kind;
+                    // if there is room in the discussion, add this message
+                    if details.discussion.len() < 10 {
+                        details.discussion.push(message_details);
+                    }
+                    // update order_details: Mapping<Hash, Order>
+                    self.order_details.insert(order_id, &details);
+
+                    // move the order from seller's orders_1shipped or orders_2delivered to orders_4problem map
+                    let mut problems = self.account_seller_orders_4problem.get(seller).unwrap_or_default();
+                    // if the seller's problem list is full, send an Error
+                    if problems.hashvector.len() > 69 {
+                        return Err(Error::StorageFull);
+                    }
+                    else if was_shipped {
+                        let mut shipped = self.account_seller_orders_1shipped.get(seller).unwrap_or_default();
+                        shipped.hashvector.retain(|value| *value != order_id);
+                        problems.hashvector.push(order_id);
+                        // update the maps
+                        self.account_seller_orders_1shipped.insert(seller, &shipped);
+                        self.account_seller_orders_4problem.insert(seller, &problems);
+                    }
+                    else {
+                        let mut delivered = self.account_seller_orders_2delivered.get(seller).unwrap_or_default();
+                        delivered.hashvector.retain(|value| *value != order_id);
+                        problems.hashvector.push(order_id);
+                        // update the maps
+                        self.account_seller_orders_2delivered.insert(seller, &delivered);
+                        self.account_seller_orders_4problem.insert(seller, &problems);
+                    }
+
+                    // update Buyer profile
+                    // account_profile_buyer: Mapping<AccountId, BuyerProfile>
+                    let mut buyerprofile = self.account_profile_buyer.get(caller).unwrap_or_default();
+                    match kind {
+                        ProblemKind::Damaged => buyerprofile.total_damaged = buyerprofile.total_damaged.saturating_add(1),
+                        ProblemKind::WrongItem => buyerprofile.total_wrong = buyerprofile.total_wrong.saturating_add(1),
+                        ProblemKind::NotReceived => buyerprofile.total_not_received = buyerprofile.total_not_received.saturating_add(1),
+                        ProblemKind::NotAsDescribed => buyerprofile.total_not_as_described = buyerprofile.total_not_as_described.saturating_add(1),
+                        ProblemKind::Counterfeit => buyerprofile.total_counterfeit = buyerprofile.total_counterfeit.saturating_add(1),
+                        ProblemKind::MissingParts => buyerprofile.total_missing_parts = buyerprofile.total_missing_parts.saturating_add(1),
+                        ProblemKind::ServiceNotRendered => buyerprofile.total_service_not_rendered = buyerprofile.total_service_not_rendered.saturating_add(1),
+                        ProblemKind::BrokenFile => buyerprofile.total_broken_file = buyerprofile.total_broken_file.saturating_add(1),
+                        ProblemKind::None => (),
+                    }
+                    if !was_shipped {
+                        buyerprofile.total_delivered = buyerprofile.total_delivered.saturating_sub(1);
+                    }
+                    self.account_profile_buyer.insert(caller, &buyerprofile);
+
+                    // update Seller profile
+                    // account_profile_seller: Mapping<AccountId, SellerProfile>
+                    let mut sellerprofile = self.account_profile_seller.get(seller).unwrap_or_default();
+                    match kind {
+                        ProblemKind::Damaged => sellerprofile.total_damaged = sellerprofile.total_damaged.saturating_add(1),
+                        ProblemKind::WrongItem => sellerprofile.total_wrong = sellerprofile.total_wrong.saturating_add(1),
+                        ProblemKind::NotReceived => sellerprofile.total_not_received = sellerprofile.total_not_received.saturating_add(1),
+                        ProblemKind::NotAsDescribed => sellerprofile.total_not_as_described = sellerprofile.total_not_as_described.saturating_add(1),
+                        ProblemKind::Counterfeit => sellerprofile.total_counterfeit = sellerprofile.total_counterfeit.saturating_add(1),
+                        ProblemKind::MissingParts => sellerprofile.total_missing_parts = sellerprofile.total_missing_parts.saturating_add(1),
+                        ProblemKind::ServiceNotRendered => sellerprofile.total_service_not_rendered = sellerprofile.total_service_not_rendered.saturating_add(1),
+                        ProblemKind::BrokenFile => sellerprofile.total_broken_file = sellerprofile.total_broken_file.saturating_add(1),
+                        ProblemKind::None => (),
+                    }
+                    if !was_shipped {
+                        sellerprofile.total_delivered = sellerprofile.total_delivered.saturating_sub(1);
+                    }
+                    self.account_profile_seller.insert(seller, &sellerprofile);
+
+                    // EMIT EVENT
+                    Self::env().emit_event(ProblemReported {
+                        order_id: order_id,
+                        buyer: caller,
+                        seller: seller,
+                        problem: kind,
+                    });
+
+                }
+                else {
+                    return Err(Error::NotEligibleToReport)
+                }
+            }
+            else {
+                return Err(Error::NotYourOrder)
+            }
+            Ok(())
+        }
+
+
+        // 8 🟢 Message The Seller
+        #[ink(message)]
+        pub fn message_the_seller (&mut self, 
+            order_id: Hash,
+            photo_or_youtube_url: Vec<u8>,
+            message: Vec<u8>,
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // if the inputs are too big, send an error
+            if photo_or_youtube_url.len() > 200 || message.len() > 200 {
+                return Err(Error::DataTooLarge);
+            }
+            // set up clones
+            let message_clone = message.clone();
+            // set up the caller
+            let caller = Self::env().caller();
+            // is this your order? account_buyer_orders: Mapping<AccountId, HashVector>
+            let myorders = self.account_buyer_orders.get(caller).unwrap_or_default();
+            if myorders.hashvector.contains(&order_id) {
+                // get the order details order_details: Mapping<Hash, Order>
+                let mut details = self.order_details.get(order_id).unwrap_or_default();
+                let now = self.env().block_timestamp();
+                // make the message_id hash
+                let encodable = (caller, now, order_id, message); // Implements `scale::Encode`
+                let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
+                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
+                let new_message_id: Hash = Hash::from(new_id_u8);
+                
+                // update message_details: Mapping<Hash, MessageDetails>
+                let message_details = MessageDetails {
+                    message_id: new_message_id,
+                    from_acct: caller,
+                    to_acct: details.seller,
+                    order_id: order_id,
+                    message: message_clone,
+                    media_url: photo_or_youtube_url,
+                    timestamp: now
+                };
+
+                // update order discussion
+                // if there is room in the discussion, add this message
+                if details.discussion.len() < 10 {
+                    details.discussion.push(message_details);
+                    // update order_details: Mapping<Hash, Order>
+                    self.order_details.insert(order_id, &details);
+                }
+                else {
+                    // if this discussion is full, send an error
+                    return Err(Error::StorageFull);
+                }
+                
+            }
+            else {
+                return Err(Error::NotYourOrder)
+            }
+            Ok(())
+        }
+
+
+        // 9 🟢 Update Buyer Account Settings
+        #[ink(message)]
+        pub fn update_buyer_account_settings (&mut self, 
+            name: Vec<u8>,
+            location: Vec<u8>
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // if the inputs are too big, send an error
+            if name.len() > 100 || location.len() > 100 {
+                return Err(Error::DataTooLarge);
+            }
+            // set up the caller
+            let caller = Self::env().caller();
+            // get the caller's Buyer Profile
+            // account_profile_buyer: Mapping<AccountId, BuyerProfile>
+            let mut profile = self.account_profile_buyer.get(caller).unwrap_or_default();
+            // update specific aspects of the BuyerProfile
+            profile.buyer_account = caller;
+            profile.buyer_name = name.clone();
+            profile.buyer_location = location.clone();
+            // update mappings
+            self.account_profile_buyer.insert(caller, &profile);
+
+            // EMIT EVENT
+            Self::env().emit_event(BuyerSettingsUpdated {
+                buyer_account: caller,
+                name: name,
+                location: location,
+            });
+
+
+            Ok(())
+        }
+ 
+
+        // 10 🟢 Update Seller Account Settings
+        #[ink(message)]
+        pub fn update_seller_account_settings (&mut self, 
+            name: Vec<u8>,
+            location: Vec<u8>,
+            description: Vec<u8>,
+            banner_url: Vec<u8>,
+            youtube_url: Vec<u8>,
+            external_link: Vec<u8>
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            if name.len() > 100 || location.len() > 100 || description.len() > 600 
+            || banner_url.len() > 200 || youtube_url.len() > 200 || external_link.len() > 200 {
+                return Err(Error::DataTooLarge);
+            }
+            // set up the caller
+            let caller = Self::env().caller();
+            // get the caller's Seller Profile
+            // account_profile_seller: Mapping<AccountId, SellerProfile>
+            let mut profile = self.account_profile_seller.get(caller).unwrap_or_default();
+            // update specific aspects of the SellerProfile
+            profile.seller_account = caller;
+            profile.seller_name = name.clone();
+            profile.seller_location = location.clone();
+            profile.store_description = description.clone();
+            profile.banner_url = banner_url.clone();
+            profile.youtube_url = youtube_url.clone();
+            profile.external_link = external_link.clone();
+            // update mappings
+            self.account_profile_seller.insert(caller, &profile);
+
+            // EMIT EVENT
+            Self::env().emit_event(SellerSettingsUpdated {
+                seller_account: caller,
+                seller_name: name,
+                store_description: description,
+                seller_location: location,
+                banner_url: banner_url,
+                youtube_url: youtube_url,
+                external_link: external_link,
+            });
+
+            Ok(())
+        }
+
+
+        // 11 🟢 Update Order Tracking Information 
+        #[ink(message)]
+        pub fn update_order_tracking_information (&mut self, 
+            order_id: Hash,
+            tracking_update: Vec<u8>,
+            shipped: bool,
+            delivered: bool
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // if the inputs are too big, send an error
+            if tracking_update.len() > 200 {
+                return Err(Error::DataTooLarge);
+            }
+            // set up the caller
+            let caller = Self::env().caller();
+            // make sure the caller is the seller on this order
+            // get the order details and compare to the seller
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.seller == caller {
+                // make sure the order is a physical product (not a service, or digital product)
+                let itemid = details.item_id;
+                if self.product_details.contains(itemid) {
+                    let item = self.product_details.get(itemid).unwrap_or_default();
+                    if !item.digital {
+
+                        details.tracking_info = tracking_update;
+
+                        if details.order_status == OrderStatus::Shipped {
+                            // seller can mark delivered, or can leave it as shipped
+                            if delivered {
+                                self.transition(&mut details, OrderStatus::Delivered, caller, b"marked delivered")?;
+                                details.time_delivered = self.env().block_timestamp();
+                                // update order_details: Mapping<Hash, Order> 
+                                self.order_details.insert(order_id, &details);
+
+                                // move this order from orders_1shipped to orders_2delivered 
+                                let mut shipped = self.account_seller_orders_1shipped.get(caller).unwrap_or_default();
+                                let mut delivered = self.account_seller_orders_2delivered.get(caller).unwrap_or_default();
+                                // if the seller's delivered list is full, kick out the oldest
+                                if delivered.hashvector.len() > 69 {
+                                    delivered.hashvector.remove(0);
+                                }
+                                shipped.hashvector.retain(|value| *value != order_id);
+                                delivered.hashvector.push(order_id);
+                                // update the maps
+                                self.account_seller_orders_2delivered.insert(caller, &delivered);
+                                self.account_seller_orders_1shipped.insert(caller, &shipped);
+
+                                // EMIT EVENT OrderDelivered
+                                Self::env().emit_event(OrderDelivered {
+                                    seller: caller,
+                                    buyer: details.buyer,
+                                    order_id: details.order_id,
+                                    order_status: OrderStatus::Delivered,
+                                    time_delivered: self.env().block_timestamp(),
+                                });
+
+                                // update Buyer profile
+                                // account_profile_buyer: Mapping<AccountId, BuyerProfile>
+                                let buyer = details.buyer;
+                                let mut buyerprofile = self.account_profile_buyer.get(buyer).unwrap_or_default();
+                                buyerprofile.total_delivered = buyerprofile.total_delivered.saturating_add(1);
+                                self.account_profile_buyer.insert(buyer, &buyerprofile);
+
+                                // update Seller profile
+                                // account_profile_seller: Mapping<AccountId, SellerProfile>
+                                let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
+                                sellerprofile.total_delivered = sellerprofile.total_delivered.saturating_add(1);
+                                self.account_profile_seller.insert(caller, &sellerprofile);
+                                
+                            }
+                        }
+
+                        if details.order_status == OrderStatus::Awaiting {
+                            // seller can mark shipped but not delivered
+                            if shipped {
+                                self.transition(&mut details, OrderStatus::Shipped, caller, b"marked shipped")?;
+                                details.time_shipped = self.env().block_timestamp();
+                                details.delivery_deadline = details.time_shipped.saturating_add(self.delivery_window);
+                                // update order_details: Mapping<Hash, Order> 
+                                self.order_details.insert(order_id, &details);
+
+                                // move this order from orders_0awaiting to orders_1shipped
+                                let mut shipped = self.account_seller_orders_1shipped.get(caller).unwrap_or_default();
+                                let mut awaiting = self.account_seller_orders_0awaiting.get(caller).unwrap_or_default();
+                                // if the seller's delivered list is full, kick out the oldest
+                                if shipped.hashvector.len() > 69 {
+                                    shipped.hashvector.remove(0);
+                                }
+                                awaiting.hashvector.retain(|value| *value != order_id);
+                                shipped.hashvector.push(order_id);
+                                // update the maps
+                                self.account_seller_orders_0awaiting.insert(caller, &awaiting);
+                                self.account_seller_orders_1shipped.insert(caller, &shipped);
+
+                                // update Seller profile
+                                // account_profile_seller: Mapping<AccountId, SellerProfile>
+                                let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
+                                sellerprofile.awaiting = sellerprofile.awaiting.saturating_sub(1);
+                                self.account_profile_seller.insert(caller, &sellerprofile);
+
+                                // EMIT EVENT OrderShipped
+                                Self::env().emit_event(OrderShipped {
+                                    seller: caller,
+                                    buyer: details.buyer,
+                                    order_id: details.order_id,
+                                    order_status: OrderStatus::Shipped,
+                                });
+
+                                // payment for this order stays in escrow until the buyer
+                                // confirms delivery or the escrow timeout expires
+                                
+                            }
+                        }
+
+                    }
+                    else {
+                        return Err(Error::NotAPhysicalProduct)
+                    }
+                }
+                else {
+                    return Err(Error::NotAPhysicalProduct)
+                }                
+            }
+            else {
+                return Err(Error::NotYourOrder)
+            }
+            Ok(())
+        }
+
+
+        // 12 🟢 Refuse An Order
+        #[ink(message)]
+        pub fn refuse_an_order (&mut self, 
+            order_id: Hash
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // set up the caller
+            let caller = Self::env().caller();
+            // make sure the caller is the seller on this order
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.seller == caller {
+                // you can only refuse an order that has not yet shipped. Status must be Awaiting.
+                if details.order_status == OrderStatus::Awaiting {
+                    
+                    // credit a refund to the buyer for this order
+                    let buyer = details.buyer;
+                    let refund: Balance = details.total_order_price;
+                    self.credit_account(buyer, order_id, refund, CreditReason::Refund);
+                    // the refund came out of escrow, so clear the escrow for this order
+                    self.remove_from_escrow(caller, order_id);
+
+                    // put the items back in inventory
+                    self.restore_inventory(&details, 2);
+
+                    // update order_details: Mapping<Hash, Order>
+                    self.transition(&mut details, OrderStatus::Refused, caller, b"refused by seller")?;
+                    self.order_details.insert(order_id, &details);
+
+                    // move the order from orders_0awaiting to orders_5refused
+                    let mut refused = self.account_seller_orders_5refused.get(caller).unwrap_or_default();
+                    let mut awaiting = self.account_seller_orders_0awaiting.get(caller).unwrap_or_default();
+                    // if the seller's refused list is full, kick out the oldest
+                    if refused.hashvector.len() > 69 {
+                        refused.hashvector.remove(0);
+                    }
+                    awaiting.hashvector.retain(|value| *value != order_id);
+                    refused.hashvector.push(order_id);
+                    // update the maps
+                    self.account_seller_orders_0awaiting.insert(caller, &awaiting);
+                    self.account_seller_orders_5refused.insert(caller, &refused);
+
+                    // update Buyer profile
+                    // account_profile_buyer: Mapping<AccountId, BuyerProfile>
+                    let mut buyerprofile = self.account_profile_buyer.get(buyer).unwrap_or_default();
+                    buyerprofile.total_refused = buyerprofile.total_refused.saturating_add(1);
+                    self.account_profile_buyer.insert(buyer, &buyerprofile);
+
+                    // update Seller profile
+                    // account_profile_seller: Mapping<AccountId, SellerProfile>
+                    let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
+                    sellerprofile.total_refused = sellerprofile.total_refused.saturating_add(1);
+                    sellerprofile.awaiting = sellerprofile.awaiting.saturating_sub(1);
+                    self.account_profile_seller.insert(caller, &sellerprofile);
+
+                    // EMIT EVENT OrderRefused 
+                    Self::env().emit_event(OrderRefused {
+                        seller: caller,
+                        buyer: details.buyer,
+                        order_id: details.order_id,
+                        order_status: OrderStatus::Refused,
+                    });
+
+                }
+                else {
+                    return Err(Error::CannotRefuse)
+                }
+            }
+            else {
+                return Err(Error::NotYourOrder)
+            }
+            Ok(())
+        }
+
+
+        // 13 🟢 Issue Refund
+        // note that refunds are issued as a resolution to a problem
+        // the refund comes out of any payment still held in escrow for the order first,
+        // the seller must send exactly the part of refund_amount that escrow does not cover
+        // a refund of everything not yet refunded closes the order right away, a smaller
+        // (partial) refund is held as an offer until the buyer accepts or declines it
+        #[ink(message, payable)]
+        pub fn issue_refund (&mut self, 
+            order_id: Hash,
+            refund_amount: Balance
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // set up the caller
+            let caller = Self::env().caller();
+            // make sure the caller is the seller on this order
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.seller == caller {
+                // you can only refund an order that has a problem and no resolution. 
+                // Status must be Problem and resolution must be None.
+                
+                if details.order_status == OrderStatus::Problem && details.resolution == Resolution::None {
+
+                    // the buyer can never get back more than they paid
+                    let refund: Balance = refund_amount;
+                    let refundable: Balance = details.total_order_price.saturating_sub(details.refunded_amount);
+                    if refund == 0 || refund > refundable {
+                        return Err(Error::InvalidRefundAmount);
+                    }
+
+                    // a new offer replaces any earlier offer, return the earlier offer to the seller
+                    self.return_refund_offer(&mut details);
+
+                    // COLLECT PAYMENT FROM THE CALLER
+                    // the 'payable' tag on this message allows the user to send any amount
+                    // escrow is locked while the order has a problem, so it still covers the refund later
+                    let escrowed = self.order_escrow.get(order_id).unwrap_or_default();
+                    if self.env().transferred_value() != refund.saturating_sub(escrowed) {
+                        return Err(Error::InvalidRefundAmount);
+                    }
+
+                    if refund == refundable {
+                        // full refund, take what escrow covers, credit the buyer and close the order
+                        self.refund_from_escrow(&details, refund);
+                        self.finish_refund(details, refund, Resolution::Refunded, caller)?;
+                    }
+                    else {
+                        // partial refund, hold it until the buyer accepts
+                        details.refund_offered = refund;
+                        self.order_details.insert(order_id, &details);
+
+                        // EMIT EVENT RefundOffered
+                        Self::env().emit_event(RefundOffered {
+                            seller: caller,
+                            buyer: details.buyer,
+                            order_id,
+                            amount: refund,
+                            total_order_price: details.total_order_price,
+                        });
+                    }
+
+                }
+                else {
+                    return Err(Error::CannotResolve)
+                }
+            }
+            else {
+                return Err(Error::NotYourOrder)
+            }
+            
+            Ok(())
+        }
+        
+        
+        // 14 🟢 Issue Replacement
+        #[ink(message)]
+        pub fn issue_replacement (&mut self, 
+            order_id: Hash,
+            tracking: Vec<u8>
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // make sure the tracking info is not too long
+            if tracking.len() > 200 {
+                return Err(Error::DataTooLarge);
+            }
+            // set up the caller
+            let caller = Self::env().caller();
+
+            // make sure the caller is the seller on this order
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.seller == caller {
+                // you can only replace an order that has a problem. Status must be Problem.
+                if details.order_status == OrderStatus::Problem && details.resolution == Resolution::None {
+                    
+                    // return any pending partial refund offer to the seller
+                    self.return_refund_offer(&mut details);
+
+                    // update order_details: Mapping<Hash, Order>
+                    // update the shipping details with the replacement item
+                    details.tracking_info = tracking;
+                    // update the resolution
+                    details.resolution = Resolution::Replaced;
+                    // update the status to resolved
+                    self.transition(&mut details, OrderStatus::Complete, caller, b"replacement sent")?;
+                    // update the mapping
+                    self.order_details.insert(order_id, &details);
+
+                    // move the order from orders_4problem to orders_3resolved
+                    let mut problem = self.account_seller_orders_4problem.get(caller).unwrap_or_default();
+                    let mut resolved = self.account_seller_orders_3resolved.get(caller).unwrap_or_default();
+                    // if the seller's refused list is full, kick out the oldest
+                    if resolved.hashvector.len() > 69 {
+                        resolved.hashvector.remove(0);
+                    }
+                    problem.hashvector.retain(|value| *value != order_id);
+                    resolved.hashvector.push(order_id);
+                    // update the maps
+                    self.account_seller_orders_4problem.insert(caller, &problem);
+                    self.account_seller_orders_3resolved.insert(caller, &resolved);
+
+                    // update Buyer profile
+                    let buyer = details.buyer;
+                    // account_profile_buyer: Mapping<AccountId, BuyerProfile>
+                    let mut buyerprofile = self.account_profile_buyer.get(buyer).unwrap_or_default();
+                    buyerprofile.total_resolved = buyerprofile.total_resolved.saturating_add(1);
+                    self.account_profile_buyer.insert(buyer, &buyerprofile);
+
+                    // update Seller profile
+                    // account_profile_seller: Mapping<AccountId, SellerProfile>
+                    let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
+                    sellerprofile.total_resolved = sellerprofile.total_resolved.saturating_add(1);
+                    self.account_profile_seller.insert(caller, &sellerprofile);
+
+                    // EMIT EVENT OrderReplaced
+                    Self::env().emit_event(OrderReplaced {
+                        seller: caller,
+                        buyer: details.buyer,
+                        order_id: details.order_id,
+                        order_status: OrderStatus::Complete,
+                        problem: details.problem,
+                        resolution: Resolution::Replaced,
+                    });
+
+                }
+                else {
+                    return Err(Error::CannotResolve)
+                }
+            }
+            else {
+                return Err(Error::NotYourOrder)
+            }
+            
+            Ok(())
+        }
+       
+       
+        // 15 🟢 Deny Resolution Request
+        #[ink(message)]
+        pub fn deny_resolution_request (&mut self, 
+            order_id: Hash,
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // set up the caller
+            let caller = Self::env().caller();
+            // make sure the caller is the seller on this order
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.seller == caller {
+                // you can only deny resolution on an order that has a problem. Status must be Problem.
+                if details.order_status == OrderStatus::Problem && details.resolution == Resolution::None {
+                    
+                    // return any pending partial refund offer to the seller
+                    self.return_refund_offer(&mut details);
+
+                    // update order_details: Mapping<Hash, Order>
+                    // the denial time opens the buyer's window to escalate to an arbiter
+                    details.resolution = Resolution::Denied;
+                    self.transition(&mut details, OrderStatus::Complete, caller, b"resolution denied")?;
+                    details.time_denied = self.env().block_timestamp();
+                    self.order_details.insert(order_id, &details);
+
+                    // move the order from orders_4problem to orders_3resolved
+                    let mut problem = self.account_seller_orders_4problem.get(caller).unwrap_or_default();
+                    let mut resolved = self.account_seller_orders_3resolved.get(caller).unwrap_or_default();
+                    // if the seller's refused list is full, kick out the oldest
+                    if resolved.hashvector.len() > 69 {
+                        resolved.hashvector.remove(0);
+                    }
+                    problem.hashvector.retain(|value| *value != order_id);
+                    resolved.hashvector.push(order_id);
+                    // update the maps
+                    self.account_seller_orders_4problem.insert(caller, &problem);
+                    self.account_seller_orders_3resolved.insert(caller, &resolved);
+
+                    // EMIT EVENT OrderResolutionDenied
+                    Self::env().emit_event(OrderResolutionDenied {
+                        seller: caller,
+                        buyer: details.buyer,
+                        order_id: details.order_id,
+                        order_status: OrderStatus::Complete,
+                        problem: details.problem,
+                        resolution: Resolution::Denied,
+                    });
+
+                }
+                else {
+                    return Err(Error::CannotResolve)
+                }
+            }
+            else {
+                return Err(Error::NotYourOrder)
+            }
+            
+            Ok(())
+        }
+        
+        
+        // 16 🟢 Message The Buyer
+        #[ink(message)]
+        pub fn message_the_buyer (&mut self, 
+            order_id: Hash,
+            photo_or_youtube_url: Vec<u8>,
+            message: Vec<u8>,
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // if the inputs are too big, send an error
+            if photo_or_youtube_url.len() > 200 || message.len() > 200 {
+                return Err(Error::DataTooLarge);
+            }
+            // set up clones
+            let message_clone = message.clone();
+            // set up the caller
+            let caller = Self::env().caller();
+            // is this your order? 
+            // get the order details from order_details: Mapping<Hash, Order>
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.seller == caller {
+                
+                let now = self.env().block_timestamp();
+                // make the message_id hash
+                let encodable = (caller, now, order_id, message); // Implements `scale::Encode`
+                let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
+                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
+                let new_message_id: Hash = Hash::from(new_id_u8);
+                
+                // update message_details: Mapping<Hash, MessageDetails>
+                let message_details = MessageDetails {
+                    message_id: new_message_id,
+                    from_acct: caller,
+                    to_acct: details.buyer,
+                    order_id: order_id,
+                    message: message_clone,
+                    media_url: photo_or_youtube_url,
+                    timestamp: now
+                };
+
+                // update order discussion
+                // if there is room in the discussion, add the message, otherwise error
+                if details.discussion.len() < 10 {
+                    details.discussion.push(message_details);
+                    // update order_details: Mapping<Hash, Order>
+                    self.order_details.insert(order_id, &details);
+                }
+                else {
+                    return Err(Error::StorageFull);
+                }
+                
+            }
+            else {
+                return Err(Error::NotYourOrder)
+            }
+            Ok(())
+        }
+
+
+        // 17 🟢 Rate A Buyer
+        #[ink(message)]
+        pub fn rate_a_buyer (&mut self, 
+            buyer: AccountId,
+            rating: u64,
+            review: Vec<u8>,
+            order_id: Hash
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // if the rating is between 1 and 5
+            if rating > 0 && rating < 6 {
+                let caller = Self::env().caller();
+                let review_clone = review.clone();
+                let now = self.env().block_timestamp();
+                
+                // get the details for this order
+                let details = self.order_details.get(order_id).unwrap_or_default();
+                // account_seller_buyers_reviewed: Mapping<AccountId, AccountVector>
+                let mut reviewed = self.account_seller_buyers_reviewed.get(caller).unwrap_or_default();
+
+                // did you actually sell this order to this buyer?
+                if details.seller == caller {
+                    // have you already reviewed this buyer recently?
+                    if reviewed.accountvector.contains(&buyer) {
+                        return Err(Error::NotEligibleToReview)
+                    }
+                    else {
+                        // make the review_id hash
+                        let encodable = (caller, buyer, review); // Implements `scale::Encode`
+                        let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
+                        ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
+                        let new_review_id: Hash = Hash::from(new_id_u8);
+
+                        // update mappings...
+                        // account_seller_buyers_reviewed: Mapping<AccountId, AccountVector>
+                        if reviewed.accountvector.len() > 399 {
+                            // remove the oldest
+                            reviewed.accountvector.remove(0);
+                        }
+                        reviewed.accountvector.push(buyer);
+                        self.account_seller_buyers_reviewed.insert(caller, &reviewed);
+
+                        // account_profile_buyer: Mapping<AccountId, BuyerProfile>
+                        let mut profile = self.account_profile_buyer.get(buyer).unwrap_or_default();
+                        let oldsum = profile.review_count.saturating_mul(profile.review_average);
+                        let newsum = oldsum.saturating_add(rating);
+                        // recalculate the review count
+                        profile.review_count = profile.review_count.saturating_add(1);
+                        // recalcualte the review average
+                        profile.review_average = newsum.div_euclid(profile.review_count);
+                        // return to storage
+                        self.account_profile_buyer.insert(buyer, &profile);
+
+                        // EMIT EVENT NewBuyerRating
+                        Self::env().emit_event(NewBuyerRating {
+                            review_id: new_review_id,
+                            buyer: buyer,
+                            reviewer: caller,
+                            rating: rating,
+                            review: review_clone,
+                            timestamp: now,
+                        });
+
+
+                    }
+                }
+                else {
+                    // if you did not buy from this seller, error
+                    return Err(Error::NotEligibleToReview)
+                }
+            }
+            else {
+                return Err(Error::RatingOutOfBounds)
+            }
+            Ok(())
+        }
+        
+        
+        // 18 🟢 Add A Product
+        #[ink(message)]
+        pub fn add_a_product (&mut self, 
+            digital: bool,
+            title: Vec<u8>,
+            price: Balance,
+            brand: Vec<u8>,
+            category: Vec<u8>,
+            description: Vec<u8>, 
+            inventory: u128, 
+            photo_or_youtube_link1: Vec<u8>, 
+            photo_or_youtube_link2: Vec<u8>,
+            photo_or_youtube_link3: Vec<u8>,
+            more_info_link: Vec<u8>,
+            delivery_info: Vec<u8>,
+            product_location: Vec<u8>,
+            digital_file_url: Vec<u8>,
+            zeno_percent: u128,
+            handling_time: u64,
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // if the inputs are too big, send an error
+            if title.len() > 200 || brand.len() > 100 || category.len() > 100 || description.len() > 600
+            || photo_or_youtube_link1.len() > 200 || photo_or_youtube_link2.len() > 200
+            || photo_or_youtube_link3.len() > 200 || more_info_link.len() > 200
+            || delivery_info.len() > 200 || product_location.len() > 200 || digital_file_url.len() > 600 {
+                return Err(Error::DataTooLarge);
+            }
+
+            // set up clones
+            let title_clone = title.clone();
+            // set up the caller
+            let caller = Self::env().caller();
+
+            let mut seller_products = self.account_seller_products.get(caller).unwrap_or_default();
+            // if the seller's product list is full, send an error
+            if seller_products.hashvector.len() > 49 {
+                return Err(Error::StorageFull);
+            }
+            else {
+
+                let now = self.env().block_timestamp();
+
+                // make the product id hash
+                let encodable = (caller, title, now); // Implements `scale::Encode`
+                let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
+                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
+                let new_product_id: Hash = Hash::from(new_id_u8);
+
+                // get the seller profile 
+                let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
+
+                // set up the product details
+                let product = Product {
+                    product_id: new_product_id,
+                    digital: digital,
+                    title: title_clone.clone(),
+                    price: price,
+                    brand: brand.clone(),
+                    category: category.clone(),
+                    seller_account: caller,
+                    seller_name: sellerprofile.seller_name.clone(),
+                    description: description.clone(), 
+                    review_average: u64::default(),
+                    review_count: u64::default(),
+                    inventory: inventory, 
+                    photo_or_youtube_link1: photo_or_youtube_link1.clone(), 
+                    photo_or_youtube_link2: photo_or_youtube_link2.clone(),
+                    photo_or_youtube_link3: photo_or_youtube_link3.clone(),
+                    more_info_link: more_info_link.clone(),
+                    delivery_info: delivery_info.clone(),
+                    product_location: product_location.clone(),
+                    digital_file_url: digital_file_url,
+                    zeno_percent: zeno_percent,
+                    zeno_buyers: <Vec<AccountId>>::default(),
+                    zeno_max_buyers: 20,
+                    zeno_curve: 0,
+                    zeno_unique_buyers: false,
+                    handling_time: handling_time,
+                    dispute_window: u64::default(),
+                };
+
+                // UPDATE MAPPINGS...
+                // total_count_products: u128
+                self.total_count_products = self.total_count_products.saturating_add(1);
+
+                // account_seller_products: Mapping<AccountId, HashVector>
+                seller_products.hashvector.push(new_product_id);
+                self.account_seller_products.insert(caller, &seller_products);
+
+                // product_details: Mapping<Hash, Product>
+                self.product_details.insert(new_product_id, &product);
+
+                // if this is the first product, set the member_since timestamp
+                if sellerprofile.member_since == u64::default() {
+                    sellerprofile.member_since = now;
+                    // add this seller to all_sellers StorageVec
+                    self.all_sellers.push(&caller);
+                }
+                self.account_profile_seller.insert(caller, &sellerprofile);
+
+                // EMIT EVENT NewProduct
+                Self::env().emit_event(NewProduct {
+                    product_id: new_product_id,
+                    digital: digital,
+                    title: title_clone,
+                    price: price,
+                    brand: brand,
+                    category: category,
+                    seller_account: caller,
+                    seller_name: sellerprofile.seller_name,
+                    description: description, 
+                    inventory: inventory, 
+                    photo_or_youtube_link1: photo_or_youtube_link1, 
+                    photo_or_youtube_link2: photo_or_youtube_link2,
+                    photo_or_youtube_link3: photo_or_youtube_link3,
+                    more_info_link: more_info_link,
+                    delivery_info: delivery_info,
+                    product_location: product_location,
+                    zeno_percent: zeno_percent,
+                    handling_time: handling_time,
+                });
+
+
+            }
+            
+            Ok(())
+        }
+
+        
+        // 19 🟢 Update Product Details 
+        #[ink(message)]
+        pub fn update_product_details (&mut self,
+            product_id: Hash, 
+            price: Balance,
+            brand: Vec<u8>,
+            category: Vec<u8>,
+            description: Vec<u8>, 
+            inventory: u128, 
+            photo_or_youtube_link1: Vec<u8>, 
+            photo_or_youtube_link2: Vec<u8>,
+            photo_or_youtube_link3: Vec<u8>,
+            more_info_link: Vec<u8>,
+            delivery_info: Vec<u8>,
+            product_location: Vec<u8>,
+            digital_file_url: Vec<u8>,
+            handling_time: u64,
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            if brand.len() > 100 || category.len() > 100 || description.len() > 600
+            || photo_or_youtube_link1.len() > 200 || photo_or_youtube_link2.len() > 200
+            || photo_or_youtube_link3.len() > 200 || more_info_link.len() > 200
+            || delivery_info.len() > 200 || product_location.len() > 200 || digital_file_url.len() > 600 {
+                return Err(Error::DataTooLarge);
+            }
+            // set up the caller
+            let caller = Self::env().caller();
+
+            // is this your product? Check the product id
+            // account_seller_products: Mapping<AccountId, HashVector>
+            let seller_products = self.account_seller_products.get(caller).unwrap_or_default();
+            if seller_products.hashvector.contains(&product_id) {
+                
+                // get the current product details
+                let details = self.product_details.get(product_id).unwrap_or_default();
+
+                // set up the product details update
+                let update = Product {
+                    product_id: product_id,
+                    digital: details.digital,
+                    title: details.title.clone(),
+                    price: price,
+                    brand: brand.clone(),
+                    category: category.clone(),
+                    seller_account: caller,
+                    seller_name: details.seller_name.clone(),
+                    description: description.clone(),
+                    review_average: details.review_average,
+                    review_count: details.review_count, 
+                    inventory: inventory, 
+                    photo_or_youtube_link1: photo_or_youtube_link1.clone(), 
+                    photo_or_youtube_link2: photo_or_youtube_link2.clone(),
+                    photo_or_youtube_link3: photo_or_youtube_link3.clone(),
+                    more_info_link: more_info_link.clone(),
+                    delivery_info: delivery_info.clone(),
+                    product_location: product_location.clone(),
+                    digital_file_url: digital_file_url,
+                    zeno_percent: details.zeno_percent,
+                    zeno_buyers: details.zeno_buyers,
+                    zeno_max_buyers: details.zeno_max_buyers,
+                    zeno_curve: details.zeno_curve,
+                    zeno_unique_buyers: details.zeno_unique_buyers,
+                    handling_time: handling_time,
+                    dispute_window: details.dispute_window,
+                };
+
+                // update product_details: Mapping<Hash, Product>
+                self.product_details.insert(product_id, &update);
+
+                // EMIT EVENT UpdatedProduct
+                Self::env().emit_event(UpdatedProduct {
+                    product_id: product_id,
+                    digital: details.digital,
+                    title: details.title,
+                    price: price,
+                    brand: brand,
+                    category: category,
+                    seller_account: caller,
+                    seller_name: details.seller_name,
+                    description: description, 
+                    inventory: inventory, 
+                    photo_or_youtube_link1: photo_or_youtube_link1, 
+                    photo_or_youtube_link2: photo_or_youtube_link2,
+                    photo_or_youtube_link3: photo_or_youtube_link3,
+                    more_info_link: more_info_link,
+                    delivery_info: delivery_info,
+                    product_location: product_location,
+                    handling_time: handling_time,
+                });
+
+            }
+            
+            else {
+                return Err(Error::NotYourProduct)
+            }
+
+            Ok(())
+        }
+        
+
+        // 20 🟢 Add A Service
+        #[ink(message)]
+        pub fn add_a_service (&mut self, 
+            online: bool,
+            title: Vec<u8>,
+            price: Balance,
+            category: Vec<u8>,
+            description: Vec<u8>,  
+            inventory: u128,
+            photo_or_youtube_link1: Vec<u8>, 
+            photo_or_youtube_link2: Vec<u8>,
+            photo_or_youtube_link3: Vec<u8>,
+            booking_link: Vec<u8>,
+            service_location: Vec<u8>,
+            zeno_percent: u128,
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            if title.len() > 200 || category.len() > 100 || description.len() > 600
+            || photo_or_youtube_link1.len() > 200 || photo_or_youtube_link2.len() > 200
+            || photo_or_youtube_link3.len() > 200 || booking_link.len() > 200
+            || service_location.len() > 200 {
+                return Err(Error::DataTooLarge);
+            }
             // set up clones
+            let title_clone = title.clone();
             // set up the caller
             let caller = Self::env().caller();
-            // get the account_store_boookmarks list
-            let mut my_list = self.account_store_bookmarks.get(caller).unwrap_or_default();
-            // if their bookmarks are full or the seller is already on the list, send an error
-            if my_list.accountvector.len() > 19 || my_list.accountvector.contains(&seller) {
+
+            let mut seller_services = self.account_seller_services.get(caller).unwrap_or_default();
+            // if the seller's service list is full, send an error
+            if seller_services.hashvector.len() > 49 {
                 return Err(Error::StorageFull);
             }
             else {
-                // if the seller is not there already, add them to the vector
-                my_list.accountvector.push(seller);
-                // update mapping account_store_bookmarks: Mapping<AccountId, AccountVector>
-                self.account_store_bookmarks.insert(caller, &my_list);
+                let now = self.env().block_timestamp();
+
+                // make the product id hash
+                let encodable = (caller, title, now); // Implements `scale::Encode`
+                let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
+                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
+                let new_service_id: Hash = Hash::from(new_id_u8);
+
+                // get the seller profile 
+                let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
+
+                // set up the service details
+                let service = Service {
+                    service_id: new_service_id,
+                    online: online,
+                    title: title_clone.clone(),
+                    price: price,
+                    category: category.clone(),
+                    seller_account: caller,
+                    seller_name: sellerprofile.seller_name.clone(),
+                    description: description.clone(),
+                    review_average: u64::default(),
+                    review_count: u64::default(),
+                    inventory: inventory,
+                    photo_or_youtube_link1: photo_or_youtube_link1.clone(), 
+                    photo_or_youtube_link2: photo_or_youtube_link2.clone(),
+                    photo_or_youtube_link3: photo_or_youtube_link3.clone(),
+                    booking_link: booking_link.clone(),
+                    service_location: service_location.clone(),
+                    zeno_percent: zeno_percent,
+                    zeno_buyers: <Vec<AccountId>>::default(),
+                    zeno_max_buyers: 20,
+                    zeno_curve: 0,
+                    zeno_unique_buyers: false,
+                    dispute_window: u64::default(),
+                };
+
+                // UPDATE MAPPINGS ...
+                // total_count_services u128
+                self.total_count_services = self.total_count_services.saturating_add(1);
+
+                // account_seller_services: Mapping<AccountId, HashVector>
+                seller_services.hashvector.push(new_service_id);
+                self.account_seller_services.insert(caller, &seller_services);
+
+                // service_details: Mapping<Hash, Service>
+                self.service_details.insert(new_service_id, &service);
+
+                // if this is the first product or service, set the member_since timestamp
+                // and add the seller to the all_sellers StorageVec
+                if sellerprofile.member_since == u64::default() {
+                    sellerprofile.member_since = now;
+                    self.all_sellers.push(&caller);
+                }
+                self.account_profile_seller.insert(caller, &sellerprofile);
+
+                // EMIT EVENT NewService
+                Self::env().emit_event(NewService {
+                    service_id: new_service_id,
+                    online: online,
+                    title: title_clone,
+                    price: price,
+                    category: category,
+                    seller_account: caller,
+                    seller_name: sellerprofile.seller_name,
+                    description: description,
+                    inventory: inventory,
+                    photo_or_youtube_link1: photo_or_youtube_link1, 
+                    photo_or_youtube_link2: photo_or_youtube_link2,
+                    photo_or_youtube_link3: photo_or_youtube_link3,
+                    booking_link: booking_link,
+                    service_location: service_location,
+                    zeno_percent: zeno_percent,
+                });
 
-                Ok(())
             }
+            
+            Ok(())
         }
+        
 
-        // 2 🟢 Remove A Store Bookmark
+        // 21 🟢 Update Service Details
         #[ink(message)]
-        pub fn remove_store_bookmark (&mut self, 
-            seller: AccountId,
+        pub fn update_service_details (&mut self,
+            service_id: Hash, 
+            price: Balance,
+            category: Vec<u8>,
+            description: Vec<u8>, 
+            inventory: u128, 
+            photo_or_youtube_link1: Vec<u8>, 
+            photo_or_youtube_link2: Vec<u8>,
+            photo_or_youtube_link3: Vec<u8>,
+            booking_link: Vec<u8>,
+            service_location: Vec<u8>,
         ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            if category.len() > 100 || description.len() > 600
+            || photo_or_youtube_link1.len() > 200 || photo_or_youtube_link2.len() > 200
+            || photo_or_youtube_link3.len() > 200 || booking_link.len() > 200
+            || service_location.len() > 200 {
+                return Err(Error::DataTooLarge);
+            }
             // set up the caller
             let caller = Self::env().caller();
-            // get the account_store_boookmarks list
-            let mut my_list = self.account_store_bookmarks.get(caller).unwrap_or_default();
-            if my_list.accountvector.contains(&seller) {
-                // remove the store account from the bookmark list
-                my_list.accountvector.retain(|value| *value != seller);
-                // update mapping account_store_bookmarks: Mapping<AccountId, AccountVector>
-                self.account_store_bookmarks.insert(caller, &my_list);
+
+            // is this your service? Check the service id
+            // account_seller_services: Mapping<AccountId, HashVector>
+            let seller_services = self.account_seller_services.get(caller).unwrap_or_default();
+            if seller_services.hashvector.contains(&service_id) {
+                
+                // get the current service details
+                let details = self.service_details.get(service_id).unwrap_or_default();
+
+                // set up the service details update
+                let update = Service {
+                    service_id: service_id,
+                    online: details.online,
+                    title: details.title.clone(),
+                    price: price,
+                    category: category.clone(),
+                    seller_account: caller,
+                    seller_name: details.seller_name.clone(),
+                    description: description.clone(),
+                    review_average: details.review_average,
+                    review_count: details.review_count,
+                    inventory: inventory,
+                    photo_or_youtube_link1: photo_or_youtube_link1.clone(), 
+                    photo_or_youtube_link2: photo_or_youtube_link2.clone(),
+                    photo_or_youtube_link3: photo_or_youtube_link3.clone(),
+                    booking_link: booking_link.clone(),
+                    service_location: service_location.clone(),
+                    zeno_percent: details.zeno_percent,
+                    zeno_buyers: details.zeno_buyers,
+                    zeno_max_buyers: details.zeno_max_buyers,
+                    zeno_curve: details.zeno_curve,
+                    zeno_unique_buyers: details.zeno_unique_buyers,
+                    dispute_window: details.dispute_window,
+                };
+
+                // update service_details: Mapping<Hash, Service>
+                self.service_details.insert(service_id, &update);
+
+                // EMIT EVENT UpdatedService
+                Self::env().emit_event(UpdatedService {
+                    service_id: service_id,
+                    online: details.online,
+                    title: details.title,
+                    price: price,
+                    category: category,
+                    seller_account: caller,
+                    seller_name: details.seller_name,
+                    description: description,
+                    inventory: inventory,
+                    photo_or_youtube_link1: photo_or_youtube_link1, 
+                    photo_or_youtube_link2: photo_or_youtube_link2,
+                    photo_or_youtube_link3: photo_or_youtube_link3,
+                    booking_link: booking_link,
+                    service_location: service_location,
+                });
+
             }
-            
+            else {
+                return Err(Error::NotYourProduct)
+            }
+
             Ok(())
         }
 
-        
-        // 3 🟢 Remove Item From Cart
+
+        // 22 🟢 Delete A Product
         #[ink(message)]
-        pub fn remove_item_from_cart (&mut self, 
-            item_id: Hash,
-        ) -> Result<(), Error> {
+        pub fn delete_a_product (&mut self, product_id_to_delete: Hash) -> Result<(), Error> {
+            self.check_not_paused(false)?;
             // set up the caller
             let caller = Self::env().caller();
-            // get the caller's current unpaid cart id
-            let mut cart = self.account_current_cart.get(caller).unwrap_or_default();
-            // get the quantity for that item in the cart
-            let mut quantity: u128 = 0;
-            for (item, number) in &cart.cart_items {
-                if *item == item_id {
-                    quantity = *number;
-                }
-            }
-            // remove item from cart_items vector
-            cart.cart_items.retain(|value| *value != (item_id, quantity));
-
-            // get the total number of items in the cart
-            let totalitems: u128 = cart.cart_items.len().try_into().unwrap();
-            // iterate through the cart items to get the total price of the cart
-            let mut carttotal: Balance = 0;
-            for (item, number) in &cart.cart_items {
-                // get the price for that item
-                let mut item_price: Balance = 0;
-                if self.product_details.contains(item) {
-                    item_price = self.product_details.get(item).unwrap_or_default().price;
-                }
-                else {
-                    if self.service_details.contains(item) {
-                        item_price = self.service_details.get(item).unwrap_or_default().price;
-                    }
-                }
-                // add the price to the total price
-                carttotal = carttotal.saturating_add(item_price.saturating_mul(*number));
+            // is this your product?
+            // get the seller's products
+            let mut products = self.account_seller_products.get(caller).unwrap_or_default();
+            if products.hashvector.contains(&product_id_to_delete) {
+                // remove this product from account_seller_products
+                products.hashvector.retain(|value| *value != product_id_to_delete);
+                self.account_seller_products.insert(caller, &products);
+                // remove this product from product_details
+                self.product_details.remove(product_id_to_delete);
+                // reduce total_count_products by one
+                self.total_count_products = self.total_count_products.saturating_sub(1);
+            }
+            else {
+                return Err(Error::NotYourProduct);
             }
- 
-            // perpare the updated UnpaidCart
-            let updated_cart = UnpaidCart {
-                buyer: caller,
-                cart_total: carttotal,
-                total_items: totalitems,
-                cart_items: cart.cart_items
-            };
- 
-            // update mappings
-            self.account_current_cart.insert(caller, &updated_cart);           
             
             Ok(())
         }
-        
-    
-        // 4 🟢 Update Cart Item Quantity
+
+        // 23 🟢 Delete A Service
         #[ink(message)]
-        pub fn update_cart_item_quantity (&mut self, 
-            item_id: Hash,
-            new_quantity: u128
-        ) -> Result<(), Error> {
+        pub fn delete_a_service (&mut self, service_id_to_delete: Hash) -> Result<(), Error> {
+            self.check_not_paused(false)?;
             // set up the caller
             let caller = Self::env().caller();
-            // get the caller's current unpaid cart id
-            let mut cart = self.account_current_cart.get(caller).unwrap_or_default();
-            // get the quantity for that item in the cart
-            let mut quantity: u128 = 0;
-            for (item, number) in &cart.cart_items {
-                if *item == item_id {
-                    quantity = *number;
-                }
+            // is this your service?
+            // get the seller's services
+            let mut services = self.account_seller_services.get(caller).unwrap_or_default();
+            if services.hashvector.contains(&service_id_to_delete) {
+                // remove this service from account_seller_services
+                services.hashvector.retain(|value| *value != service_id_to_delete);
+                self.account_seller_services.insert(caller, &services);
+                // remove this service from service_details
+                self.service_details.remove(service_id_to_delete);
+                // reduce total_count_services by one
+                self.total_count_services = self.total_count_services.saturating_sub(1);
             }
-            // remove item and old quantity from the cart_items vector
-            cart.cart_items.retain(|value| *value != (item_id, quantity));
-            // add the item with the new quantity to the cart_items vector
-            cart.cart_items.push((item_id, new_quantity));
+            else {
+                return Err(Error::NotYourProduct);
+            }
+            
+            Ok(())
+        }
 
-            // get the total number of items in the cart
-            let totalitems: u128 = cart.cart_items.len().try_into().unwrap();
-            // iterate through the cart items to get the total price of the cart
-            let mut carttotal: Balance = 0;
-            for (item, number) in &cart.cart_items {
-                // get the price for that item
-                let mut item_price: Balance = 0;
-                if self.product_details.contains(item) {
-                    item_price = self.product_details.get(item).unwrap_or_default().price;
-                }
-                else {
-                    if self.service_details.contains(item) {
-                        item_price = self.service_details.get(item).unwrap_or_default().price;
+
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> PRIMARY GET MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+ 
+
+        // 24 🟢 Search Products By Keyword
+        #[ink(message)]
+        pub fn search_products_by_keyword (&self,
+            keywords1: Vec<u8>,
+            keywords2: Vec<u8>,
+            keywords3: Vec<u8>
+        ) -> ProductSearchResults {
+            // set up the search targets
+            let targetvecu81 = keywords1.clone();
+            let target_string1 = String::from_utf8(targetvecu81).unwrap_or_default();
+            let targetvecu82 = keywords2.clone();
+            let target_string2 = String::from_utf8(targetvecu82).unwrap_or_default();
+            let targetvecu83 = keywords3.clone();
+            let target_string3 = String::from_utf8(targetvecu83).unwrap_or_default();
+
+            // set up return structures
+            let mut product_results = <Vec<PublicProduct>>::default();
+
+            // iterate over all_sellers: StorageVec<AccountId> to find matching results
+            if self.all_sellers.len() > 0 {
+                for i in 0..self.all_sellers.len() {
+                    let seller = self.all_sellers.get(i).unwrap();
+                    // get the seller's products
+                    let seller_products = self.account_seller_products.get(seller).unwrap_or_default();
+                    for item in seller_products.hashvector.iter() {
+                        // get the details
+                        let details = self.product_details.get(item).unwrap_or_default();
+                        // check to see if the keywords are there
+                        let title_string = String::from_utf8(details.title.clone()).unwrap_or_default();
+                        let seller_string = String::from_utf8(details.seller_name.clone()).unwrap_or_default();
+                        let brand_string = String::from_utf8(details.brand.clone()).unwrap_or_default();
+                        let category_string = String::from_utf8(details.category.clone()).unwrap_or_default();
+                        let description_string = String::from_utf8(details.description.clone()).unwrap_or_default();
+                        let delivery_string = String::from_utf8(details.delivery_info.clone()).unwrap_or_default();
+                        let location_string = String::from_utf8(details.product_location.clone()).unwrap_or_default();
+
+                        // if the target_string is in the details
+                        if (title_string.contains(&target_string1) || brand_string.contains(&target_string1) ||
+                        category_string.contains(&target_string1) || description_string.contains(&target_string1) ||
+                        delivery_string.contains(&target_string1) || location_string.contains(&target_string1) ||
+                        seller_string.contains(&target_string1))
+                        && (title_string.contains(&target_string2) || brand_string.contains(&target_string2) ||
+                        category_string.contains(&target_string2) || description_string.contains(&target_string2) ||
+                        delivery_string.contains(&target_string2) || location_string.contains(&target_string2) ||
+                        seller_string.contains(&target_string2))
+                        && (title_string.contains(&target_string3) || brand_string.contains(&target_string3) ||
+                        category_string.contains(&target_string3) || description_string.contains(&target_string3) ||
+                        delivery_string.contains(&target_string3) || location_string.contains(&target_string3) ||
+                        seller_string.contains(&target_string3)) {
+                            
+                            // make the public product structure
+                            let public_product = PublicProduct {
+                                product_id: details.product_id,
+                                digital: details.digital,
+                                title: details.title,
+                                price: details.price,
+                                brand: details.brand,
+                                category: details.category,
+                                seller_account: details.seller_account,
+                                seller_name: details.seller_name,
+                                description: details.description,
+                                review_average: details.review_average,
+                                review_count: details.review_count,
+                                inventory: details.inventory, 
+                                photo_or_youtube_link1: details.photo_or_youtube_link1, 
+                                photo_or_youtube_link2: details.photo_or_youtube_link2,
+                                photo_or_youtube_link3: details.photo_or_youtube_link3,
+                                more_info_link: details.more_info_link,
+                                delivery_info: details.delivery_info,
+                                product_location: details.product_location,
+                                zeno_percent: details.zeno_percent,
+                                zeno_buyers: details.zeno_buyers,
+                                zeno_max_buyers: details.zeno_max_buyers,
+                                zeno_curve: details.zeno_curve,
+                                zeno_unique_buyers: details.zeno_unique_buyers,
+                                handling_time: details.handling_time,
+                                dispute_window: details.dispute_window
+                            };
+
+                            // add it to the results vector
+                            product_results.push(public_product);
+                        }
+                        // continue iterating on this seller's products
                     }
+                    // continue iterating on all the sellers
                 }
-                // add the price to the total price
-                carttotal = carttotal.saturating_add(item_price.saturating_mul(*number));
             }
- 
-            // perpare the updated UnpaidCart
-            let updated_cart = UnpaidCart {
-                buyer: caller,
-                cart_total: carttotal,
-                total_items: totalitems,
-                cart_items: cart.cart_items
+
+            // package the results
+            let results = ProductSearchResults {
+                search: vec![keywords1, keywords2, keywords3],
+                products: product_results
             };
- 
-            // update mappings
-            self.account_current_cart.insert(caller, &updated_cart);   
-            
-            Ok(())
+
+            // return the results
+            results
         }
 
-        
-        // 5 🟢 Checkout Cart
-        #[ink(message, payable)]
-        pub fn checkout_cart (&mut self, 
-            deliver_to_address: Vec<u8>
-        ) -> Result<(), Error> {
-            // make sure the address is not too long
-            if deliver_to_address.len() > 300 {
-                return Err(Error::DataTooLarge);
+
+        // 25 🟢 Search Services By Keyword
+        #[ink(message)]
+        pub fn search_services_by_keyword (&self,
+            keywords1: Vec<u8>,
+            keywords2: Vec<u8>,
+            keywords3: Vec<u8>
+        ) -> ServiceSearchResults {
+            // set up search targets
+            let targetvecu81 = keywords1.clone();
+            let target_string1 = String::from_utf8(targetvecu81).unwrap_or_default();
+            let targetvecu82 = keywords2.clone();
+            let target_string2 = String::from_utf8(targetvecu82).unwrap_or_default();
+            let targetvecu83 = keywords3.clone();
+            let target_string3 = String::from_utf8(targetvecu83).unwrap_or_default();
+
+            // set up return structures
+            let mut service_results = <Vec<Service>>::default();
+
+            // iterate over all_sellers: StorageVec<AccountId> to find matching results
+            if self.all_sellers.len() > 0 {
+                for i in 0..self.all_sellers.len() {
+                    let seller = self.all_sellers.get(i).unwrap();
+                    // get the seller's services
+                    let seller_services = self.account_seller_services.get(seller).unwrap_or_default();
+                    for item in seller_services.hashvector.iter() {
+                        // get the details
+                        let details = self.service_details.get(item).unwrap_or_default();
+                        // check to see if the keywords are there
+                        let title_string = String::from_utf8(details.title.clone()).unwrap_or_default();
+                        let seller_string = String::from_utf8(details.seller_name.clone()).unwrap_or_default();
+                        let category_string = String::from_utf8(details.category.clone()).unwrap_or_default();
+                        let description_string = String::from_utf8(details.description.clone()).unwrap_or_default();
+                        let location_string = String::from_utf8(details.service_location.clone()).unwrap_or_default();
+
+                        // if the target_string is in the details
+                        if (title_string.contains(&target_string1) || seller_string.contains(&target_string1) ||
+                        category_string.contains(&target_string1) || description_string.contains(&target_string1) ||
+                        location_string.contains(&target_string1)) 
+                        && (title_string.contains(&target_string2) || seller_string.contains(&target_string2) ||
+                        category_string.contains(&target_string2) || description_string.contains(&target_string2) ||
+                        location_string.contains(&target_string2)) 
+                        && (title_string.contains(&target_string3) || seller_string.contains(&target_string3) ||
+                        category_string.contains(&target_string3) || description_string.contains(&target_string3) ||
+                        location_string.contains(&target_string3)) {
+                            // add it to the results vector
+                            service_results.push(details);
+                        }
+                        // continue iterating over this seller's services
+                    }
+                    // continue iterating over the other sellers
+                }
             }
 
-            // set up the caller and timestamp
-            let caller = Self::env().caller();
-            let rightnow = self.env().block_timestamp();
+            // package the results
+            let results = ServiceSearchResults {
+                search: vec![keywords1, keywords2, keywords3],
+                services: service_results
+            };
+
+            // return the results
+            results
+        }
+        
+        
+        // 26 🟢 Search Stores by Keyword
+        #[ink(message)]
+        pub fn search_stores_by_keyword (&self,
+            keywords1: Vec<u8>,
+            keywords2: Vec<u8>,
+            keywords3: Vec<u8>
+        ) -> StoreSearchResults {
+            // set up search targets
+            let targetvecu81 = keywords1.clone();
+            let target_string1 = String::from_utf8(targetvecu81).unwrap_or_default();
+            let targetvecu82 = keywords2.clone();
+            let target_string2 = String::from_utf8(targetvecu82).unwrap_or_default();
+            let targetvecu83 = keywords3.clone();
+            let target_string3 = String::from_utf8(targetvecu83).unwrap_or_default();
+
+            // set up return structures
+            let mut store_results = <Vec<SellerProfile>>::default();
 
-            // get the caller's unpaid cart
-            let current_cart = self.account_current_cart.get(caller).unwrap_or_default();
+            // iterate over all_sellers: StorageVec<AccountId> to find matching results
+            if self.all_sellers.len() > 0 {
+                for i in 0..self.all_sellers.len() {
+                    let acct = self.all_sellers.get(i).unwrap();
+                    // get the profile
+                    let profile = self.account_profile_seller.get(acct).unwrap_or_default();
+                    
+                    // check to see if the keywords are there
+                    let name_string = String::from_utf8(profile.seller_name.clone()).unwrap_or_default();
+                    let description_string = String::from_utf8(profile.store_description.clone()).unwrap_or_default();
+                    let location_string = String::from_utf8(profile.seller_location.clone()).unwrap_or_default();
 
-            // UPDATE THE CART TOTAL AND REMOVE ITEMS THAT DO NOT HAVE ENOUGH INVENTORY
-            // make a new cart items vector to work with
-            let mut final_cart_items = <Vec<(Hash, u128)>>::default();
-            let mut item_inventory: u128 = 0;
-            let mut item_price: Balance = 0;
-            let mut carttotal: Balance = 0;
-            // iterate through the cart to keep only items that have enough inventory
-            for (item, number) in &current_cart.cart_items {
-                // get the inventory and price for that item
-                if self.product_details.contains(item) {
-                    item_inventory = self.product_details.get(item).unwrap_or_default().inventory;
-                    item_price = self.product_details.get(item).unwrap_or_default().price;
-                }
-                else {
-                    if self.service_details.contains(item) {
-                        item_inventory = self.service_details.get(item).unwrap_or_default().inventory;
-                        item_price = self.service_details.get(item).unwrap_or_default().price;
+                    // if the target_string is in the profile
+                    if (name_string.contains(&target_string1) || description_string.contains(&target_string1) ||
+                    location_string.contains(&target_string1))
+                    && (name_string.contains(&target_string2) || description_string.contains(&target_string2) ||
+                    location_string.contains(&target_string2))
+                    && (name_string.contains(&target_string3) || description_string.contains(&target_string3) ||
+                    location_string.contains(&target_string3)) {
+                        // add it to the results vector
+                        store_results.push(profile);
                     }
                 }
-                // if the item has enough inventory, add it to the official cart items
-                if item_inventory >= *number {
-                    // add this item to the total price
-                    carttotal = carttotal.saturating_add(item_price.saturating_mul(*number));
-                    // add this item and quantity to the final cart items vector
-                    final_cart_items.push((*item, *number));
-                } 
-            } 
-            
-            // COLLECT PAYMENT FROM THE CALLER
-            // the 'payable' tag on this message allows the user to send any amount
-            let amount_paid: Balance = self.env().transferred_value();
-            if amount_paid < carttotal {
-                // error, did not pay enough
-                return Err(Error::InsufficientPayment);
+                //continue iterating on the other sellers
             }
-            else {
-                // make the cart_id hash
-                let encodable = (caller, rightnow); // Implements `scale::Encode`
-                let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
-                let new_cart_id: Hash = Hash::from(new_id_u8);
 
-                // set up the vector of orders and items that will be referenced later
-                let mut all_cart_orders = <Vec<Order>>::default();
-                let mut all_cart_items = <Vec<Hash>>::default();
-                let mut total_items_count = u128::default();
-                let mut total_orders_count = u128::default();
+            // package the results
+            let results = StoreSearchResults {
+                search: vec![keywords1, keywords2, keywords3],
+                stores: store_results
+            };
 
-                // FOR EACH ITEM IN THE CART ...
-                for (item, number) in &final_cart_items {
+            // return the results
+            results
+        }
 
-                    // set up clones
-                    let deliver_to_address_clone1 = deliver_to_address.clone();
-                    
-                    // CREATE THE ORDER STRUCT FOR THIS ITEM...
 
-                    let mut item_seller: AccountId = AccountId::from([0x0; 32]);
-                    let mut item_seller_name: Vec<u8> = <Vec<u8>>::default();
-                    let mut item_image:Vec<u8> = <Vec<u8>>::default();
-                    let mut item_name: Vec<u8> = <Vec<u8>>::default();
-                    let mut item_price: Balance = 0;
-                    let mut item_zeno_percent: u128 = 0;
-                    let mut item_is_digital: bool = false;
-                    let mut item_is_service: bool = false;
+        // 27 🟢 View My Orders
+        #[ink(message)]
+        pub fn view_my_orders (&self) -> ViewBuyerOrders {
+            // set the caller
+            let caller = Self::env().caller();
+            // set up return structures
+            let mut myorders = <Vec<Order>>::default();
+            
+            // get the buyer's completed Orders...
+            // get the vector of order ids from storage
+            let order_ids = self.account_buyer_orders.get(caller).unwrap_or_default();
+            // get the details of each order and add them to the Vec<Order> myorders
+            for id in order_ids.hashvector.iter() {
+                // order_details: Mapping<Hash, Order>
+                let orderdetails = self.order_details.get(id).unwrap_or_default();
+                myorders.push(orderdetails);
+            }
 
-                    // get the details for this item
-                    if self.product_details.contains(item) {
-                        let mut details = self.product_details.get(item).unwrap_or_default();
-                        item_seller = details.seller_account;
-                        item_seller_name = details.seller_name.clone();
-                        item_image = details.photo_or_youtube_link1.clone();
-                        item_name = details.title.clone();
-                        item_price = details.price;
-                        item_zeno_percent = details.zeno_percent;
-                        item_is_digital = details.digital; 
+            // package the results
+            let my_orders = ViewBuyerOrders {
+                buyer: caller,
+                carts: myorders,
+            };
 
-                        // update zeno buyers information for the item...
-                        // if there are fewer than 20 zeno buyers on the list
-                        if details.zeno_buyers.len() < 20 {
-                            // add this buyer to the zeno buyers list
-                            details.zeno_buyers.push(caller);
-                        }
+            // return the results
+            my_orders
+        }
+        
 
-                        // reduce the inventory on this item by the quantity bought
-                        details.inventory = details.inventory.saturating_sub(*number);
+        // 28 🟢 View My (Buyer) Account
+        // Front end: if the product/service inventory is zero, note as unavailable
+        #[ink(message)]
+        pub fn view_my_buyer_account (&self) -> ViewBuyerAccount {
+            // set the caller
+            let caller = Self::env().caller();
+            // get the buyer profile from account_profile_buyer: Mapping<AccountId, BuyerProfile>
+            let buyerprofile = self.account_profile_buyer.get(caller).unwrap_or_default();
 
-                        // update the product details map
-                        self.product_details.insert(item, &details);
+            // set up return structures
+            let mut bookmarkedstores = <Vec<SellerProfile>>::default();
+            let mut downloads = <Vec<Download>>::default();
+            let mut myorders = <Vec<Order>>::default();
 
-                    }
-                    else {
-                        if self.service_details.contains(item) {
-                            item_is_service = true;
-                            let mut details = self.service_details.get(item).unwrap_or_default();
-                            item_seller = details.seller_account;
-                            item_seller_name = details.seller_name.clone();
-                            item_image = details.photo_or_youtube_link1.clone();
-                            item_name = details.title.clone();
-                            item_price = details.price;
-                            item_zeno_percent = details.zeno_percent;
+            // get bookmarked store accounts from account_store_bookmarks: Mapping<AccountId, AccountVector>
+            let store_accounts = self.account_store_bookmarks.get(caller).unwrap_or_default();
+            // for each seller account get the account_profile_seller: Mapping<AccountId, SellerProfile>
+            for seller in store_accounts.accountvector.iter() {
+                let profile = self.account_profile_seller.get(seller).unwrap_or_default();
+                // add that profile to the bookmarkedstores vector of profiles
+                bookmarkedstores.push(profile);
+            }
 
-                            // update zeno information for the item, 
-                            // if there are fewer than 20 zeno buyers on the list
-                            if details.zeno_buyers.len() < 20 {
-                                // add this buyer to the zeno buyers list
-                                details.zeno_buyers.push(caller);
-                            }
+            // get the digital product ids
+            let digital_ids = self.account_owned_digital_items.get(caller).unwrap_or_default();
+            // for each digital item owned, get the details and create the Download struct
+            for id in digital_ids.hashvector.iter() {
+                // get the product details
+                let details = self.product_details.get(id).unwrap_or_default();
+                // create the Download struct for that id
+                let download_item = Download {
+                    product_id: details.product_id,
+                    title: details.title,
+                    brand: details.brand,
+                    seller_account: details.seller_account,
+                    seller_name: details.seller_name,
+                    description: details.description,
+                    photo: details.photo_or_youtube_link1,
+                    more_info: details.more_info_link,
+                    file_url: details.digital_file_url,
+                };
+                // add the download_item to the downloads vector
+                downloads.push(download_item);
+            }
 
-                            // reduce the inventory on this item by the quantity bought
-                            details.inventory = details.inventory.saturating_sub(*number);
+            // get the buyer's Orders...
+            // get the vector of order ids from storage
+            let order_ids = self.account_buyer_orders.get(caller).unwrap_or_default();
+            // get the details of each order and add them to the Vec<Order> myorders
+            for id in order_ids.hashvector.iter() {
+                // order_details: Mapping<Hash, Order>
+                let orderdetails = self.order_details.get(id).unwrap_or_default();
+                myorders.push(orderdetails);
+            }
 
-                            // update the service details map
-                            self.service_details.insert(item, &details);
+            // package the results
+            let my_account = ViewBuyerAccount {
+                buyer: buyerprofile,
+                bookmarked_stores: bookmarkedstores,
+                digital_downloads: downloads,
+                orders: myorders,
+            };
 
-                        }
-                        else {
-                            // error, item does not exist
-                            return Err(Error::ItemDoesNotExist)
-                        }
-                    }
+            // return the results
+            my_account
+        }
 
-                    // make the order_id hash
-                    let encodable = (caller, rightnow, item); // Implements `scale::Encode`
-                    let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                    ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
-                    let new_order_id: Hash = Hash::from(new_id_u8);
-                    
-                    // calculate the order total
-                    let item_order_total: Balance = number.saturating_mul(item_price);
 
-                    // calculate the zeno total
-                    let item_zeno_total: Balance = item_order_total.saturating_mul(item_zeno_percent).saturating_div(100); 
+        // 29 🟢 View My (Unpaid) Cart
+        #[ink(message)]
+        pub fn view_my_cart (&self) -> ViewUnpaidCart {
+            // set the caller
+            let caller = Self::env().caller();
+            // get the callers current unpaid cart from account_current_cart: Mapping<AccountId, UnpaidCart>
+            let current_cart = self.account_current_cart.get(caller).unwrap_or_default();
 
-                    // account for alternate order status when the product is digital
-                    let mut status: u8 = 0;
-                    if item_is_digital || item_is_service {
-                        status = 2;
-                    }
+            // set up return structures
+            let mut cartproducts = <Vec<UnpaidCartProduct>>::default();
+            let mut cartservices = <Vec<UnpaidCartService>>::default();
+            let mut carttotal_products: Balance = 0;
+            let mut carttotal_services: Balance = 0;
 
-                    // get the buyer profile
-                    let mut buyer_profile = self.account_profile_buyer.get(caller).unwrap_or_default();
+            // each item in current_cart.cart_items looks like (Hash, u128) meaning (itemid, quantity)
+            // for each item, determine product or service
+            for (item, number) in current_cart.cart_items.iter() {
+                if self.product_details.contains(item) {
+                    // get the product details
+                    let productdetails = self.product_details.get(item).unwrap_or_default();
+                    let zenobuyers = productdetails.zeno_buyers.len().try_into().unwrap();
 
-                    // set up the Order structure
-                    let mut new_order = Order {
-                        order_id: new_order_id,
-                        cart_id: new_cart_id,
-                        order_timestamp: rightnow,
-                        buyer: caller,
-                        buyer_rating: buyer_profile.review_average,
-                        buyer_rating_count: buyer_profile.review_count,
-                        seller: item_seller,
-                        seller_name: item_seller_name,
-                        image: item_image,
-                        item_id: *item,
-                        item_name: item_name,
+                    // make the UnpaidCartProduct structure
+                    let unpaidproduct = UnpaidCartProduct {
+                        product_id: *item,
                         quantity: *number,
-                        price_each: item_price,
-                        total_order_price: item_order_total,
-                        deliver_to_address: deliver_to_address_clone1,
-                        deliver_to_account: caller,
-                        tracking_info: <Vec<u8>>::default(),
-                        order_status: status, 
-                        time_delivered: u64::default(),
-                        discussion: <Vec<MessageDetails>>::default(),
-                        problem: 0,
-                        resolution: 0,
-                        zeno_total: item_zeno_total
+                        digital: productdetails.digital,
+                        title: productdetails.title,
+                        price: productdetails.price,
+                        brand: productdetails.brand,
+                        seller_account: productdetails.seller_account,
+                        seller_name: productdetails.seller_name,
+                        photo_or_youtube_link1: productdetails.photo_or_youtube_link1,
+                        inventory: productdetails.inventory, 
+                        delivery_info: productdetails.delivery_info,
+                        product_location: productdetails.product_location,
+                        zeno_percent: productdetails.zeno_percent,
+                        zeno_buyers: zenobuyers
                     };
 
-                    // SPECIAL ACTIONS FOR DIGITAL PRODUCTS...
-                    // if the item is a digital product, send ownership to the buyer and pay the seller
-                    if item_is_digital {
-                        // get this account's set of owned digital items
-                        let mut owned = self.account_owned_digital_items.get(caller).unwrap_or_default();
-                        // is this item already in the owned list?
-                        if owned.hashvector.contains(item) {
-                            // do nothing
-                        }
-                        else {
-                            // if the owner already has 400 digital dowloads in storage, remove the oldest
-                            if owned.hashvector.len() > 399 {
-                                owned.hashvector.remove(0);
-                            }
-                            owned.hashvector.push(*item);
-                            // update account_owned_digital_items: Mapping<AccountId, HashVector>
-                            self.account_owned_digital_items.insert(caller, &owned);
-                        }
-                        
-                        // mark the order as delivered
-                        new_order.time_delivered = rightnow;
-                        
-                        // payout the seller for the digital product
-                        if self.env().transfer(item_seller, item_order_total).is_err() {
-                            return Err(Error::PayoutFailed);
-                        }
-                    }
+                    // add that to the cartproducts vector
+                    cartproducts.push(unpaidproduct);
 
-                    // PAYOUT SERVICES
-                    if item_is_service {
-                        // mark the order as delivered
-                        new_order.time_delivered = rightnow;
-                        
-                        // payout the seller for the service
-                        // self.env().transfer(item_seller, item_order_total).expect("payout failed");
-                        if self.env().transfer(item_seller, item_order_total).is_err() {
-                            return Err(Error::PayoutFailed);
-                        }
+                    // add the price to the cart total for products IF there is enough inventory
+                    if productdetails.inventory >= *number {
+                        carttotal_products = carttotal_products.saturating_add(productdetails.price.saturating_mul(*number));
                     }
+                    
+                }
+                else {
+                    if self.service_details.contains(item) {
+                        // get the service details
+                        let servicedetails = self.service_details.get(item).unwrap_or_default();
+                        let zenobuyers = servicedetails.zeno_buyers.len().try_into().unwrap();
 
-                    // update order_details: Mapping<Hash, Order>
-                    self.order_details.insert(new_order_id, &new_order);
-                    // update all_orders: Vec<Hash> keep the 490 most recent
-                    if self.all_orders.len() > 489 {
-                        // kick out the oldest
-                        self.all_orders.remove(0);
-                    }
-                    self.all_orders.push(new_order_id);
-                    // increase total_count_orders by one
-                    self.total_count_orders = self.total_count_orders.saturating_add(1);
+                        // make the UnpaidCartService structure
+                        let unpaidservice = UnpaidCartService {
+                            service_id: *item,
+                            quantity: *number,
+                            online: servicedetails.online,
+                            title: servicedetails.title,
+                            price: servicedetails.price,
+                            seller_account: servicedetails.seller_account,
+                            seller_name: servicedetails.seller_name,
+                            photo_or_youtube_link1: servicedetails.photo_or_youtube_link1,
+                            booking_link: servicedetails.booking_link,
+                            inventory: servicedetails.inventory, 
+                            service_location: servicedetails.service_location,
+                            zeno_percent: servicedetails.zeno_percent,
+                            zeno_buyers: zenobuyers
+                        };
 
-                    // update all_cart_orders, all_cart_items, total_items_count, and total_orders_count
-                    all_cart_orders.push(new_order);
-                    all_cart_items.push(*item);
-                    total_items_count = total_items_count.saturating_add(*number);
-                    total_orders_count = total_orders_count.saturating_add(1);
-                                        
-                    // update account_buyer_orders: Mapping<AccountId, HashVector>
-                    let mut buyer_orders = self.account_buyer_orders.get(caller).unwrap_or_default();
-                    // if there are more than 24 orders here, remove the oldest
-                    if buyer_orders.hashvector.len() > 24 {
-                        buyer_orders.hashvector.remove(0);
-                    }
-                    buyer_orders.hashvector.push(new_order_id);
-                    self.account_buyer_orders.insert(caller, &buyer_orders);
+                        // add that to the cartservices vector
+                        cartservices.push(unpaidservice);
 
-                    // update account_buyer_items_bought: Mapping<AccountId, HashVector>
-                    let mut buyer_items = self.account_buyer_items_bought.get(caller).unwrap_or_default();
-                    if buyer_items.hashvector.contains(item) {
-                        // do nothing
-                    }
-                    else {
-                        // if there are more than 399 items here, remove the oldest
-                        if buyer_items.hashvector.len() > 399 {
-                            buyer_items.hashvector.remove(0);
+                        // add the price to the cart total for services IF there is enough invetory
+                        if servicedetails.inventory >= *number {
+                            carttotal_services = carttotal_services.saturating_add(servicedetails.price.saturating_mul(*number));
                         }
-                        buyer_items.hashvector.push(*item);
-                        self.account_buyer_items_bought.insert(caller, &buyer_items);
-                    }
 
-                    // update account_seller_orders: Mapping<AccountId, HashVector> based on status (0 or 2)
-                    if status == 0 {
-                        let mut seller_orders = self.account_seller_orders_0awaiting.get(item_seller).unwrap_or_default();
-                        // if the seller_orders.hashvector AWAITING is full, send error
-                        if seller_orders.hashvector.len() > 69 && status == 0 {
-                            return Err(Error::StorageFull);
-                        }
-                        // otherwise, add this order and update the mapping
-                        seller_orders.hashvector.push(new_order_id);
-                        self.account_seller_orders_0awaiting.insert(item_seller, &seller_orders);
-                    }
-                    if status == 2 {
-                        let mut seller_orders = self.account_seller_orders_2delivered.get(item_seller).unwrap_or_default();
-                        // if the seller_orders.hashvector DELIVERED full, remove the oldest
-                        if seller_orders.hashvector.len() > 69 && status == 2 {
-                            // remove the oldest
-                            seller_orders.hashvector.remove(0);
-                        }
-                        // add this order and update the mapping
-                        seller_orders.hashvector.push(new_order_id);
-                        self.account_seller_orders_2delivered.insert(item_seller, &seller_orders);
                     }
-                    
-                    // update account_profile_seller: Mapping<AccountId, SellerProfile>
-                    let mut seller_profile = self.account_profile_seller.get(item_seller).unwrap_or_default();
-                    // increment total_orders
-                    seller_profile.total_orders = seller_profile.total_orders.saturating_add(1);
-                    if item_is_digital || item_is_service {
-                        seller_profile.total_delivered = seller_profile.total_delivered.saturating_add(1);
-                    }
-                    // update the total awaiting orders
-                    let awaiting_now = self.account_seller_orders_0awaiting.get(item_seller).unwrap_or_default().hashvector.len();
-                    seller_profile.awaiting = awaiting_now.try_into().unwrap();
-                    // update the map 
-                    self.account_profile_seller.insert(item_seller, &seller_profile);
+                }
+            }
 
-                    // update account_profile_buyer: Mapping<AccountId, BuyerProfile>
-                    // increment total_orders
-                    buyer_profile.total_orders = buyer_profile.total_orders.saturating_add(1);
-                    if item_is_digital || item_is_service {
-                        buyer_profile.total_delivered = buyer_profile.total_delivered.saturating_add(1);
-                    }
-                    // if this is the first order, set the member_since timestamp
-                    // and add one to the total_count_buyers
-                    if buyer_profile.member_since == u64::default() {
-                        buyer_profile.member_since = rightnow;
-                        self.total_count_buyers = self.total_count_buyers.saturating_add(1);
-                    }
-                    self.account_profile_buyer.insert(caller, &buyer_profile);
+            // the cart total is the total of all items in the cart for which there
+            // is sufficient inventory to fulfil the order if you order right now
+            let carttotal: Balance = carttotal_products.saturating_add(carttotal_services);
+            
+            // package the results
+            let my_cart = ViewUnpaidCart {
+                buyer: caller,
+                cart_total: carttotal,
+                total_items: current_cart.total_items,
+                cart_products: cartproducts,
+                cart_services: cartservices
+            };
 
-                    // EMIT EVENT to register the order to the chain
-                    Self::env().emit_event(OrderPlaced {
-                        order_id: new_order_id,
-                        order_timestamp: rightnow,
-                        buyer: caller,
-                        seller: item_seller,
-                        total_order_price: item_order_total,
-                    });
+            // return the results
+            my_cart
+        }
 
-                    // EMIT EVENT For DIGITAL DOWNLOAD OWNERSHIP
-                    if item_is_digital {
-                        Self::env().emit_event(DigitalDownload {
-                            buyer: caller,
-                            product_id: *item,
-                            order_timestamp: rightnow,
-                        });
-                    }
-                }
 
-                // UPDATE CART RELATED STORAGE MAPPINGS...
+        // 30 🟢 Go To Store
+        #[ink(message)]
+        pub fn go_to_store (&self,
+            seller: AccountId
+        ) -> ViewStore {
+            // get the seller's profile from account_profile_seller: Mapping<AccountId, SellerProfile>
+            let store_owner = self.account_profile_seller.get(seller).unwrap_or_default();
+            // set up return structures
+            let mut store_products = <Vec<PublicProduct>>::default();
+            let mut store_services = <Vec<Service>>::default();
 
-                // update account_profile_buyer: Mapping<AccountId, BuyerProfile>
-                let mut buyer_profile = self.account_profile_buyer.get(caller).unwrap_or_default();
-                // increment total_carts and total_orders
-                buyer_profile.total_carts = buyer_profile.total_carts.saturating_add(1);
-                self.account_profile_buyer.insert(caller, &buyer_profile);
+            // get the seller's products from account_seller_products: Mapping<AccountId, HashVector>
+            let product_ids = self.account_seller_products.get(seller).unwrap_or_default();
+            for id in product_ids.hashvector.iter() {
+                // get the product details struct and add it to the store_products vector
+                let details = self.product_details.get(id).unwrap_or_default();
+                // make the public product structure
+                let public_product = PublicProduct {
+                    product_id: details.product_id,
+                    digital: details.digital,
+                    title: details.title,
+                    price: details.price,
+                    brand: details.brand,
+                    category: details.category,
+                    seller_account: details.seller_account,
+                    seller_name: details.seller_name,
+                    description: details.description,
+                    review_average: details.review_average,
+                    review_count: details.review_count,
+                    inventory: details.inventory, 
+                    photo_or_youtube_link1: details.photo_or_youtube_link1, 
+                    photo_or_youtube_link2: details.photo_or_youtube_link2,
+                    photo_or_youtube_link3: details.photo_or_youtube_link3,
+                    more_info_link: details.more_info_link,
+                    delivery_info: details.delivery_info,
+                    product_location: details.product_location,
+                    zeno_percent: details.zeno_percent,
+                    zeno_buyers: details.zeno_buyers,
+                    zeno_max_buyers: details.zeno_max_buyers,
+                    zeno_curve: details.zeno_curve,
+                    zeno_unique_buyers: details.zeno_unique_buyers,
+                    handling_time: details.handling_time,
+                    dispute_window: details.dispute_window
+                };
+                store_products.push(public_product);
+            }
 
-                // delete caller's unpaid cart in account_current_cart: Mapping<AccountId, UnpaidCart>
-                self.account_current_cart.remove(caller);
+            // get the seller's services from account_seller_services: Mapping<AccountId, HashVector> 
+            let service_ids = self.account_seller_services.get(seller).unwrap_or_default();
+            for id in service_ids.hashvector.iter() {
+                // get the service details struct and add it to the store_service vector
+                let servicedetails = self.service_details.get(id).unwrap_or_default();
+                store_services.push(servicedetails);
+            }
+
+            // package the results
+            let view_store = ViewStore {
+                owner: store_owner,
+                products: store_products,
+                services: store_services
+            };
+
+            // return the results
+            view_store
+        }
+
+
+        // 31 🟢 View My Seller Account - PROFILE, PRODUCTS and SERVICES
+        #[ink(message)]
+        pub fn view_my_seller_profile (&self) -> ViewSellerAccount {
+            // set the caller
+            let caller = Self::env().caller();
+            let store_owner = self.account_profile_seller.get(caller).unwrap_or_default();
+            let mut store_products = <Vec<Product>>::default();
+            let mut store_services = <Vec<Service>>::default();
 
-                Ok(())
+            // get the seller's products from account_seller_products: Mapping<AccountId, HashVector>
+            let product_ids = self.account_seller_products.get(caller).unwrap_or_default();
+            for id in product_ids.hashvector.iter() {
+                // get the product details struct and add it to the store_products vector
+                let productdetails = self.product_details.get(id).unwrap_or_default();
+                store_products.push(productdetails);
             }
-        }
 
-        
-        // 6 🟢 Rate A Product or Service
-        #[ink(message)]
-        pub fn rate_a_product_or_service (&mut self, 
-            item_id: Hash,
-            rating: u64,
-            review: Vec<u8>
-        ) -> Result<(), Error> {
-            // if the X if full, send an error
-            if review.len() > 600 {
-                return Err(Error::DataTooLarge);
+            // get the seller's services from account_seller_services: Mapping<AccountId, HashVector> 
+            let service_ids = self.account_seller_services.get(caller).unwrap_or_default();
+            for id in service_ids.hashvector.iter() {
+                // get the service details struct and ad it to the store_service vector
+                let servicedetails = self.service_details.get(id).unwrap_or_default();
+                store_services.push(servicedetails);
             }
-            // if the rating is between 1 and 5
-            if rating > 0 && rating < 6 {
-                // set up the caller
-                let caller = Self::env().caller();
-                let now = self.env().block_timestamp();
 
-                // account_buyer_items_bought: Mapping<AccountId, HashVector>
-                let bought = self.account_buyer_items_bought.get(caller).unwrap_or_default();
-                // account_buyer_items_reviewed: Mapping<AccountId, HashVector>
-                let mut reviewed = self.account_buyer_items_reviewed.get(caller).unwrap_or_default();
-                if bought.hashvector.contains(&item_id) {
-                    // did you already review it?, if so, error
-                    if reviewed.hashvector.contains(&item_id) {
-                        return Err(Error::NotEligibleToReview)
-                    }
-                    else {
-                        // make the review_id hash
-                        let encodable = (caller, item_id); // Implements `scale::Encode`
-                        let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                        ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
-                        let new_review_id: Hash = Hash::from(new_id_u8);
-                        
-                        // update mappings...
-                        // account_buyer_items_reviewed: Mapping<AccountId, HashVector>
-                        // if the reviewed hashvector if full, send an error
-                        if reviewed.hashvector.len() > 399 {
-                            // remove the oldest
-                            reviewed.hashvector.remove(0);
-                        }
-                        reviewed.hashvector.push(item_id);
-                        self.account_buyer_items_reviewed.insert(caller, &reviewed);
-                        
-                        if self.product_details.contains(item_id) {
-                            // update product_details: Mapping<Hash, Product>
-                            let mut details = self.product_details.get(item_id).unwrap_or_default();
-                            let oldsum = details.review_count.saturating_mul(details.review_average);
-                            let newsum = oldsum.saturating_add(rating);
-                            // recalculate the review count
-                            details.review_count = details.review_count.saturating_add(1);
-                            // recalcualte the review average
-                            details.review_average = newsum.div_euclid(details.review_count);
-                            // return to storage
-                            self.product_details.insert(item_id, &details);
+            // return the results
+            let results = ViewSellerAccount {
+                owner: store_owner,
+                products: store_products,
+                services: store_services
+            };
+            
+            results
+        }
 
-                            // add this review to the list of all reviews for this seller on their profile
-                            // instead of seller ratings, just use the aggregate of all product and service ratings
-                            // get the seller
-                            let seller = details.seller_account;
-                            // get the seller profile
-                            // account_profile_seller: Mapping<AccountId, SellerProfile>
-                            let mut profile = self.account_profile_seller.get(seller).unwrap_or_default();
-                            let oldsum = profile.review_count.saturating_mul(profile.review_average);
-                            let newsum = oldsum.saturating_add(rating);
-                            // recalculate the review count
-                            profile.review_count = profile.review_count.saturating_add(1);
-                            // recalcualte the review average
-                            profile.review_average = newsum.div_euclid(profile.review_count);
-                            // return to storage
-                            self.account_profile_seller.insert(seller, &profile);
 
-                        }
-                        else {
-                            if self.service_details.contains(item_id) {
-                                // update service_details: Mapping<Hash, Service>
-                                let mut details = self.service_details.get(item_id).unwrap_or_default();
-                                let oldsum = details.review_count.saturating_mul(details.review_average);
-                                let newsum = oldsum.saturating_add(rating);
-                                // recalculate the review count
-                                details.review_count = details.review_count.saturating_add(1);
-                                // recalcualte the review average
-                                details.review_average = newsum.div_euclid(details.review_count);
-                                // return to storage
-                                self.service_details.insert(item_id, &details);
+        // 32 🟢 View My Seller Account - ORDERS - AWAITING
+        #[ink(message)]
+        pub fn view_my_seller_orders_awaiting (&self) -> Vec<Order> {
+            // set the caller
+            let caller = Self::env().caller();
+            // set up return structures
+            let mut store_orders = <Vec<Order>>::default();
 
-                                // add this review to the list of all reviews for this seller on their profile
-                                // instead of seller ratings, just use the aggregate of all product and service ratings
-                                // get the seller
-                                let seller = details.seller_account;
-                                // get the seller profile
-                                // account_profile_seller: Mapping<AccountId, SellerProfile>
-                                let mut profile = self.account_profile_seller.get(seller).unwrap_or_default();
-                                let oldsum = profile.review_count.saturating_mul(profile.review_average);
-                                let newsum = oldsum.saturating_add(rating);
-                                // recalculate the review count
-                                profile.review_count = profile.review_count.saturating_add(1);
-                                // recalcualte the review average
-                                profile.review_average = newsum.div_euclid(profile.review_count);
-                                // return to storage
-                                self.account_profile_seller.insert(seller, &profile);
+            // get the seller's orders from account_seller_orders: Mapping<AccountId, HashVector>
+            let order_ids0 = self.account_seller_orders_0awaiting.get(caller).unwrap_or_default();
+            for id in order_ids0.hashvector.iter() {
+                // get the order details and add it to the store_orders vector order_details: Mapping<Hash, Order>
+                let orderdetails = self.order_details.get(id).unwrap_or_default();
+                store_orders.push(orderdetails);
+            }
 
-                            }
-                            else {
-                                return Err(Error::ItemDoesNotExist)
-                            }
-                        }
+            // return the results
+            store_orders
+        }
 
-                        // EMIT EVENT NewProductRating
-                        Self::env().emit_event(NewProductRating {
-                            review_id: new_review_id,
-                            item_id: item_id,
-                            reviewer: caller,
-                            rating: rating,
-                            review: review,
-                            timestamp: now,
-                        });
+        // 33 🟢 View My Seller Account - ORDERS - SHIPPED
+        #[ink(message)]
+        pub fn view_my_seller_orders_shipped (&self) -> Vec<Order> {
+            // set the caller
+            let caller = Self::env().caller();
+            // set up return structures
+            let mut store_orders = <Vec<Order>>::default();
 
-                    }
-                }
-                else {
-                    // if you did not buy it, error
-                    return Err(Error::NotEligibleToReview)
-                }
-            }
-            else {
-                return Err(Error::RatingOutOfBounds)
+            let order_ids1 = self.account_seller_orders_1shipped.get(caller).unwrap_or_default();
+            for id in order_ids1.hashvector.iter() {
+                // get the order details and add it to the store_orders vector order_details: Mapping<Hash, Order>
+                let orderdetails = self.order_details.get(id).unwrap_or_default();
+                store_orders.push(orderdetails);
             }
-            Ok(())
+            
+            // return the results
+            store_orders
         }
 
-
-        // 7 🟢 Report Problem Damaged
+        // 34 🟢 View My Seller Account - ORDERS - DELIVERED
         #[ink(message)]
-        pub fn report_problem_damaged (&mut self, 
-            order_id: Hash,
-            problem_photo_or_youtube_url: Vec<u8>,
-            message: Vec<u8>,
-        ) -> Result<(), Error> {
-            // if the inputs are too big, send an error
-            if problem_photo_or_youtube_url.len() > 200 || message.len() > 200 {
-                return Err(Error::DataTooLarge);
-            }
-            // set up clones
-            let message_clone = message.clone();
-            // set up the caller
+        pub fn view_my_seller_orders_delivered (&self) -> Vec<Order> {
+            // set the caller
             let caller = Self::env().caller();
-            // is this your order? account_buyer_orders: Mapping<AccountId, HashVector>
-            let myorders = self.account_buyer_orders.get(caller).unwrap_or_default();
-            if myorders.hashvector.contains(&order_id) {
-                // get the order details order_details: Mapping<Hash, Order>
-                let mut details = self.order_details.get(order_id).unwrap_or_default();
-                let seller = details.seller;
-                // Check the status. To report a problem, the status must be delivered (2)
-                // and it must have been less than 24 hours since it was marked delivered
-                let now = self.env().block_timestamp();
-                let time_since_delivered = now.saturating_sub(details.time_delivered);
-                if time_since_delivered < 86400000 && details.order_status == 2 {
-                    // make the message_id hash
-                    let encodable = (caller, now, order_id, message); // Implements `scale::Encode`
-                    let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                    ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
-                    let new_message_id: Hash = Hash::from(new_id_u8);
-                    
-                    // update message_details: Mapping<Hash, MessageDetails>
-                    let message_details = MessageDetails {
-                        message_id: new_message_id,
-                        from_acct: caller,
-                        to_acct: seller,
-                        order_id: order_id,
-                        message: message_clone,
-                        media_url: problem_photo_or_youtube_url,
-                        timestamp: now
-                    };
-                    
-                    // update order details
-                    details.order_status = 4;
-                    details.problem = 1;
-                    // if there is room in the discussion, add this message
-                    if details.discussion.len() < 10 {
-                        details.discussion.push(message_details);
-                    }
-                    // update order_details: Mapping<Hash, Order>
-                    self.order_details.insert(order_id, &details);
+            // set up return structures
+            let mut store_orders = <Vec<Order>>::default();
 
-                    // move the order from seller's orders_2delivered to orders_4problem map
-                    let mut delivered = self.account_seller_orders_2delivered.get(seller).unwrap_or_default();
-                    let mut problems = self.account_seller_orders_4problem.get(seller).unwrap_or_default();
-                    // if the seller's problem list is full, send an Error
-                    if problems.hashvector.len() > 69 {
-                        return Err(Error::StorageFull);
-                    }
-                    else {
-                        delivered.hashvector.retain(|value| *value != order_id);
-                        problems.hashvector.push(order_id);
-                        // update the maps
-                        self.account_seller_orders_2delivered.insert(seller, &delivered);
-                        self.account_seller_orders_4problem.insert(seller, &problems);
-                    }
-                    
-                    // update Buyer profile
-                    // account_profile_buyer: Mapping<AccountId, BuyerProfile>
-                    let mut buyerprofile = self.account_profile_buyer.get(caller).unwrap_or_default();
-                    buyerprofile.total_damaged = buyerprofile.total_damaged.saturating_add(1);
-                    buyerprofile.total_delivered = buyerprofile.total_delivered.saturating_sub(1);
-                    self.account_profile_buyer.insert(caller, &buyerprofile);
+            // get the seller's orders from account_seller_orders: Mapping<AccountId, HashVector>
+            let order_ids2 = self.account_seller_orders_2delivered.get(caller).unwrap_or_default();
+            for id in order_ids2.hashvector.iter() {
+                // get the order details and add it to the store_orders vector order_details: Mapping<Hash, Order>
+                let orderdetails = self.order_details.get(id).unwrap_or_default();
+                store_orders.push(orderdetails);
+            }
+
+            // return the results
+            store_orders
+        }
+
+        // 35 🟢 View My Seller Account - ORDERS - RESOLVED
+        #[ink(message)]
+        pub fn view_my_seller_orders_resolved (&self) -> Vec<Order> {
+            // set the caller
+            let caller = Self::env().caller();
+            // set up return structures
+            let mut store_orders = <Vec<Order>>::default();
 
-                    // update Seller profile
-                    // account_profile_seller: Mapping<AccountId, SellerProfile>
-                    let mut sellerprofile = self.account_profile_seller.get(seller).unwrap_or_default();
-                    sellerprofile.total_damaged = sellerprofile.total_damaged.saturating_add(1);
-                    sellerprofile.total_delivered = sellerprofile.total_delivered.saturating_sub(1);
-                    self.account_profile_seller.insert(seller, &sellerprofile);
+            // get the seller's orders from account_seller_orders: Mapping<AccountId, HashVector>
+            let order_ids3 = self.account_seller_orders_3resolved.get(caller).unwrap_or_default();
+            for id in order_ids3.hashvector.iter() {
+                // get the order details and add it to the store_orders vector order_details: Mapping<Hash, Order>
+                let orderdetails = self.order_details.get(id).unwrap_or_default();
+                store_orders.push(orderdetails);
+            }
 
-                    // EMIT EVENT
-                    Self::env().emit_event(ProblemReported {
-                        order_id: order_id,
-                        buyer: caller,
-                        seller: seller,
-                        problem: 1,
-                    });
+            // return the results
+            store_orders
+        }
 
-                }
-                else {
-                    return Err(Error::NotEligibleToReport)
-                }
+        // 36 🟢 View My Seller Account - ORDERS - PROBLEM
+        #[ink(message)]
+        pub fn view_my_seller_orders_problem (&self) -> Vec<Order> {
+            // set the caller
+            let caller = Self::env().caller();
+            // set up return structures
+            let mut store_orders = <Vec<Order>>::default();
+
+            // get the seller's orders from account_seller_orders: Mapping<AccountId, HashVector>
+            let order_ids4 = self.account_seller_orders_4problem.get(caller).unwrap_or_default();
+            for id in order_ids4.hashvector.iter() {
+                // get the order details and add it to the store_orders vector order_details: Mapping<Hash, Order>
+                let orderdetails = self.order_details.get(id).unwrap_or_default();
+                store_orders.push(orderdetails);
             }
-            else {
-                return Err(Error::NotYourOrder)
+
+            // return the results
+            store_orders
+        }
+
+        // 37 🟢 View My Seller Account - ORDERS - REFUSED
+        #[ink(message)]
+        pub fn view_my_seller_orders_refused (&self) -> Vec<Order> {
+            // set the caller
+            let caller = Self::env().caller();
+            // set up return structures
+            let mut store_orders = <Vec<Order>>::default();
+
+            // get the seller's orders from account_seller_orders: Mapping<AccountId, HashVector>
+            let order_ids5 = self.account_seller_orders_5refused.get(caller).unwrap_or_default();
+            for id in order_ids5.hashvector.iter() {
+                // get the order details and add it to the store_orders vector order_details: Mapping<Hash, Order>
+                let orderdetails = self.order_details.get(id).unwrap_or_default();
+                store_orders.push(orderdetails);
             }
-            Ok(())
+
+            // return the results
+            store_orders
         }
 
 
-        // 8 🟢 Report Problem Wrong Item 
+        // 38 🟢 Get Market Statistics
+        // get various stats about all sellers, all buyers, all products, all services, all orders for analysis
         #[ink(message)]
-        pub fn report_problem_wrong_item (&mut self, 
-            order_id: Hash,
-            problem_photo_or_youtube_url: Vec<u8>,
-            message: Vec<u8>,
-        ) -> Result<(), Error> {
-            // if the inputs are too big, send an error
-            if problem_photo_or_youtube_url.len() > 200 || message.len() > 200 {
-                return Err(Error::DataTooLarge);
-            }
-            // set up clones
-            let message_clone = message.clone();
-            // set up the caller
+        pub fn get_market_statistics (&self) -> MarketStatistics {
+            // set the caller
             let caller = Self::env().caller();
-            // is this your order? account_buyer_orders: Mapping<AccountId, HashVector>
-            let myorders = self.account_buyer_orders.get(caller).unwrap_or_default();
-            if myorders.hashvector.contains(&order_id) {
-                // get the order details order_details: Mapping<Hash, Order>
-                let mut details = self.order_details.get(order_id).unwrap_or_default();
-                let seller = details.seller;
-                // Check the status. To report a problem, the status must be delivered
-                // and it must have been less than 24 hours since it was marked delivered
-                let now = self.env().block_timestamp();
-                let time_since_delivered = now.saturating_sub(details.time_delivered);
-                if time_since_delivered < 86400000 && details.order_status == 2 {
-                    // make the message_id hash
-                    let encodable = (caller, now, order_id, message); // Implements `scale::Encode`
-                    let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                    ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
-                    let new_message_id: Hash = Hash::from(new_id_u8);
-                    
-                    // update message_details: Mapping<Hash, MessageDetails>
-                    let message_details = MessageDetails {
-                        message_id: new_message_id,
-                        from_acct: caller,
-                        to_acct: seller,
-                        order_id: order_id,
-                        message: message_clone,
-                        media_url: problem_photo_or_youtube_url,
-                        timestamp: now
-                    };
+            let rightnow = self.env().block_timestamp();
+            // set up return structures
+            let seller_count = self.all_sellers.len().try_into().unwrap();
+            let buyer_count = self.total_count_buyers;
+            let product_count = self.total_count_products;
+            let service_count = self.total_count_services;
+            let order_count = self.total_count_orders;
+            let mut orderdata = <Vec<OrderData>>::default();
+            
+            // for each order id, get the details from order_details: Mapping<Hash, Order>
+            for id in self.all_orders.iter() {
+                let details = self.order_details.get(id).unwrap_or_default();
+                let data = OrderData {
+                    timestamp: details.order_timestamp,
+                    total: details.total_order_price,
+                    status: details.order_status,
+                    problem: details.problem,
+                    resolution: details.resolution,
+                };
+                orderdata.push(data);
+            }
 
-                    // update order details
-                    details.order_status = 4;
-                    details.problem = 2;
-                    // if there is room in the discussion, add this message
-                    if details.discussion.len() < 10 {
-                        details.discussion.push(message_details);
-                    }
-                    // update order_details: Mapping<Hash, Order>
-                    self.order_details.insert(order_id, &details);
+            // package the results
+            let stats = MarketStatistics {
+                called_by: caller,
+                timestamp: rightnow,
+                number_of_sellers: seller_count,
+                number_of_buyers: buyer_count,
+                number_of_products: product_count,
+                number_of_services: service_count,
+                number_of_orders: order_count,
+                orders: orderdata,
+            };
 
-                    // move the order from seller's orders_2delivered to orders_4problem map
-                    let mut delivered = self.account_seller_orders_2delivered.get(seller).unwrap_or_default();
-                    let mut problems = self.account_seller_orders_4problem.get(seller).unwrap_or_default();
-                    // if the seller's problem list is full, send an Error
-                    if problems.hashvector.len() > 69 {
-                        return Err(Error::StorageFull);
-                    }
-                    else {
-                        delivered.hashvector.retain(|value| *value != order_id);
-                        problems.hashvector.push(order_id);
-                        // update the maps
-                        self.account_seller_orders_2delivered.insert(seller, &delivered);
-                        self.account_seller_orders_4problem.insert(seller, &problems);
-                    }
+            // return the results
+            stats
+        }
 
-                    // update Buyer profile
-                    // account_profile_buyer: Mapping<AccountId, BuyerProfile>
-                    let mut buyerprofile = self.account_profile_buyer.get(caller).unwrap_or_default();
-                    buyerprofile.total_wrong = buyerprofile.total_wrong.saturating_add(1);
-                    buyerprofile.total_delivered = buyerprofile.total_delivered.saturating_sub(1);
-                    self.account_profile_buyer.insert(caller, &buyerprofile);
 
-                    // update Seller profile
-                    // account_profile_seller: Mapping<AccountId, SellerProfile>
-                    let mut sellerprofile = self.account_profile_seller.get(seller).unwrap_or_default();
-                    sellerprofile.total_wrong = sellerprofile.total_wrong.saturating_add(1);
-                    sellerprofile.total_delivered = sellerprofile.total_delivered.saturating_sub(1);
-                    self.account_profile_seller.insert(seller, &sellerprofile);
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> SECONDARY MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
-                    // EMIT EVENT
-                    Self::env().emit_event(ProblemReported {
-                        order_id: order_id,
-                        buyer: caller,
-                        seller: seller,
-                        problem: 2,
-                    });
 
-                }
-                else {
-                    return Err(Error::NotEligibleToReport)
-                }
+        // 39 🟢 Verify That An Account Has Set Up Buyer and/or Seller Info
+        #[ink(message)]
+        pub fn verify_account (&self, verify: AccountId) -> (u8, u8) {
+            // set up return structures
+            let mut buyer: u8 = 0;
+            let mut seller: u8 = 0;
+            // check the account_profile_buyer map
+            if self.account_profile_buyer.contains(verify) {
+                buyer = 1;
             }
-            else {
-                return Err(Error::NotYourOrder)
+            if self.account_profile_seller.contains(verify) {
+                seller = 1;
             }
-            Ok(())
+            // return the results
+            let result: (u8, u8) = (buyer, seller);
+            result
         }
-        
 
-        // 9 🟢 Report Problem Not Received
+
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> ESCROW MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+
+        // 40 🟢 Release Escrow
+        // the buyer can release the payment once the order has shipped,
+        // anyone can release it once the escrow timeout has passed since shipping
         #[ink(message)]
-        pub fn report_problem_not_received (&mut self, 
-            order_id: Hash,
-            problem_photo_or_youtube_url: Vec<u8>,
-            message: Vec<u8>,
+        pub fn release_escrow (&mut self,
+            order_id: Hash
         ) -> Result<(), Error> {
-            // if the inputs are too big, send an error
-            if problem_photo_or_youtube_url.len() > 200 || message.len() > 200 {
-                return Err(Error::DataTooLarge);
-            }
-            // set up clones
-            let message_clone = message.clone();
-            // set up the caller
+            self.check_not_paused(false)?;
+            // set up the caller and timestamp
             let caller = Self::env().caller();
-            // is this your order? account_buyer_orders: Mapping<AccountId, HashVector>
-            let myorders = self.account_buyer_orders.get(caller).unwrap_or_default();
-            if myorders.hashvector.contains(&order_id) {
-                // get the order details order_details: Mapping<Hash, Order>
-                let mut details = self.order_details.get(order_id).unwrap_or_default();
-                let seller = details.seller;
-                // Check the status. To report a problem, the status must be delivered
-                // and it must have been less than 24 hours since it was marked delivered
-                let now = self.env().block_timestamp();
-                let time_since_delivered = now.saturating_sub(details.time_delivered);
-                if time_since_delivered < 86400000 && details.order_status == 2 {
-                    // make the message_id hash
-                    let encodable = (caller, now, order_id, message); // Implements `scale::Encode`
-                    let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                    ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
-                    let new_message_id: Hash = Hash::from(new_id_u8);
-                    
-                    // update message_details: Mapping<Hash, MessageDetails>
-                    let message_details = MessageDetails {
-                        message_id: new_message_id,
-                        from_acct: caller,
-                        to_acct: seller,
-                        order_id: order_id,
-                        message: message_clone,
-                        media_url: problem_photo_or_youtube_url,
-                        timestamp: now
-                    };
+            let rightnow = self.env().block_timestamp();
+            // is there anything held in escrow for this order?
+            let amount = self.order_escrow.get(order_id).unwrap_or_default();
+            if amount == 0 {
+                return Err(Error::NothingInEscrow);
+            }
+            // get the order details order_details: Mapping<Hash, Order>
+            let details = self.order_details.get(order_id).unwrap_or_default();
+            // escrow can only be released on orders that have shipped and have no open problem
+            if details.order_status != OrderStatus::Shipped && details.order_status != OrderStatus::Delivered && details.order_status != OrderStatus::Complete {
+                return Err(Error::EscrowLocked);
+            }
+            // or that are waiting on an arbiter
+            if details.arbitration == Arbitration::Escalated {
+                return Err(Error::EscrowLocked);
+            }
+            // the buyer can release at any time, everyone else must wait for the timeout
+            let release_time = self.escrow_release_time(&details);
+            if caller != details.buyer && rightnow < release_time {
+                return Err(Error::EscrowLocked);
+            }
 
-                    // update order details
-                    details.order_status = 4;
-                    details.problem = 3;
-                    // if there is room in the discussion, add this message
-                    if details.discussion.len() < 10 {
-                        details.discussion.push(message_details);
-                    }
-                    // update order_details: Mapping<Hash, Order>
-                    self.order_details.insert(order_id, &details);
+            // clear the escrow for this order, then pay out the seller and zeno buyers
+            self.release_order_escrow(&details, caller);
+
+            Ok(())
+        }
+
+
+        // 41 🟢 View Escrow By Seller
+        // shows every order payment currently held in escrow for a seller
+        #[ink(message)]
+        pub fn view_escrow_by_seller (&self,
+            seller: AccountId
+        ) -> ViewEscrow {
+            // set up return structures
+            let mut escrowed = <Vec<EscrowedOrder>>::default();
+            let mut total: Balance = 0;
+
+            // get the seller's escrowed orders from account_seller_escrow: Mapping<AccountId, HashVector>
+            let order_ids = self.account_seller_escrow.get(seller).unwrap_or_default();
+            for id in order_ids.hashvector.iter() {
+                let amount = self.order_escrow.get(id).unwrap_or_default();
+                let details = self.order_details.get(id).unwrap_or_default();
+                let mut release_time: u64 = 0;
+                if details.time_shipped > 0 {
+                    release_time = self.escrow_release_time(&details);
+                }
+                escrowed.push(EscrowedOrder {
+                    order_id: *id,
+                    amount,
+                    order_status: details.order_status,
+                    time_shipped: details.time_shipped,
+                    release_time,
+                });
+                total = total.saturating_add(amount);
+            }
+
+            // package the results
+            ViewEscrow {
+                seller,
+                total_escrowed: total,
+                orders: escrowed
+            }
+        }
 
-                    // move the order from seller's orders_2delivered to orders_4problem map
-                    let mut delivered = self.account_seller_orders_2delivered.get(seller).unwrap_or_default();
-                    let mut problems = self.account_seller_orders_4problem.get(seller).unwrap_or_default();
-                    // if the seller's problem list is full, send an Error
-                    if problems.hashvector.len() > 69 {
-                        return Err(Error::StorageFull);
-                    }
-                    else {
-                        delivered.hashvector.retain(|value| *value != order_id);
-                        problems.hashvector.push(order_id);
-                        // update the maps
-                        self.account_seller_orders_2delivered.insert(seller, &delivered);
-                        self.account_seller_orders_4problem.insert(seller, &problems);
-                    }
 
-                    // update Buyer profile
-                    // account_profile_buyer: Mapping<AccountId, BuyerProfile>
-                    let mut buyerprofile = self.account_profile_buyer.get(caller).unwrap_or_default();
-                    buyerprofile.total_not_received = buyerprofile.total_not_received.saturating_add(1);
-                    buyerprofile.total_delivered = buyerprofile.total_delivered.saturating_sub(1);
-                    self.account_profile_buyer.insert(caller, &buyerprofile);
+        // 42 🟢 View Escrow For An Order
+        #[ink(message)]
+        pub fn view_order_escrow (&self, order_id: Hash) -> Balance {
+            self.order_escrow.get(order_id).unwrap_or_default()
+        }
 
-                    // update Seller profile
-                    // account_profile_seller: Mapping<AccountId, SellerProfile>
-                    let mut sellerprofile = self.account_profile_seller.get(seller).unwrap_or_default();
-                    sellerprofile.total_not_received = sellerprofile.total_not_received.saturating_add(1);
-                    sellerprofile.total_delivered = sellerprofile.total_delivered.saturating_sub(1);
-                    self.account_profile_seller.insert(seller, &sellerprofile);
 
-                    // EMIT EVENT
-                    Self::env().emit_event(ProblemReported {
-                        order_id: order_id,
-                        buyer: caller,
-                        seller: seller,
-                        problem: 3,
-                    });
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> ORDER COMPLETION MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
-                }
-                else {
-                    return Err(Error::NotEligibleToReport)
-                }
+
+        // 43 🟢 Confirm Order Received
+        // the buyer confirms a shipped or delivered order arrived, which completes
+        // the order and releases any escrowed payment to the seller
+        #[ink(message)]
+        pub fn confirm_order_received (&mut self,
+            order_id: Hash
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // set up the caller
+            let caller = Self::env().caller();
+            // get the order details order_details: Mapping<Hash, Order>
+            let details = self.order_details.get(order_id).unwrap_or_default();
+            // is this your order?
+            if details.buyer != caller {
+                return Err(Error::NotYourOrder);
             }
-            else {
-                return Err(Error::NotYourOrder)
+            // the order must be Shipped or Delivered
+            if details.order_status != OrderStatus::Shipped && details.order_status != OrderStatus::Delivered {
+                return Err(Error::CannotComplete);
             }
+
+            self.complete_order(details, caller)?;
+
             Ok(())
         }
 
 
-        // 10 🟢 Message The Seller
+        // 44 🟢 Finalize Expired Orders
+        // anyone can complete a seller's delivered orders once the
+        // problem reporting window has passed (see problem_window_start)
         #[ink(message)]
-        pub fn message_the_seller (&mut self, 
-            order_id: Hash,
-            photo_or_youtube_url: Vec<u8>,
-            message: Vec<u8>,
+        pub fn finalize_expired_orders (&mut self,
+            seller: AccountId
         ) -> Result<(), Error> {
-            // if the inputs are too big, send an error
-            if photo_or_youtube_url.len() > 200 || message.len() > 200 {
-                return Err(Error::DataTooLarge);
+            self.check_not_paused(false)?;
+            // set up the caller and timestamp
+            let caller = Self::env().caller();
+            let rightnow = self.env().block_timestamp();
+            // get the seller's delivered orders account_seller_orders_2delivered: Mapping<AccountId, HashVector>
+            let mut delivered = self.account_seller_orders_2delivered.get(seller).unwrap_or_default();
+            // the delivered list drops its oldest orders when full, so also sweep the
+            // seller's escrow list for delivered orders that are still holding a payment
+            let escrowed = self.account_seller_escrow.get(seller).unwrap_or_default();
+            for id in escrowed.hashvector.iter() {
+                if !delivered.hashvector.contains(id) {
+                    delivered.hashvector.push(*id);
+                }
             }
-            // set up clones
-            let message_clone = message.clone();
-            // set up the caller
+            for id in delivered.hashvector.iter() {
+                let details = self.order_details.get(id).unwrap_or_default();
+                let window_closed = rightnow >= self.problem_window_start(&details).saturating_add(details.dispute_window);
+                if details.order_status == OrderStatus::Delivered && window_closed {
+                    self.complete_order(details, caller)?;
+                }
+            }
+
+            Ok(())
+        }
+
+
+        // 45 🟢 Quote Checkout
+        // runs the same inventory and price checks as checkout_cart without changing anything
+        // so the front end knows exactly what to send and what has changed in the cart
+        #[ink(message)]
+        pub fn quote_checkout (&self) -> CheckoutQuote {
+            // set the caller
             let caller = Self::env().caller();
-            // is this your order? account_buyer_orders: Mapping<AccountId, HashVector>
-            let myorders = self.account_buyer_orders.get(caller).unwrap_or_default();
-            if myorders.hashvector.contains(&order_id) {
-                // get the order details order_details: Mapping<Hash, Order>
-                let mut details = self.order_details.get(order_id).unwrap_or_default();
-                let now = self.env().block_timestamp();
-                // make the message_id hash
-                let encodable = (caller, now, order_id, message); // Implements `scale::Encode`
-                let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
-                let new_message_id: Hash = Hash::from(new_id_u8);
-                
-                // update message_details: Mapping<Hash, MessageDetails>
-                let message_details = MessageDetails {
-                    message_id: new_message_id,
-                    from_acct: caller,
-                    to_acct: details.seller,
-                    order_id: order_id,
-                    message: message_clone,
-                    media_url: photo_or_youtube_url,
-                    timestamp: now
-                };
+            // get the caller's unpaid cart
+            let current_cart = self.account_current_cart.get(caller).unwrap_or_default();
+            let (final_cart_items, dropped_items, carttotal) = self.sort_cart_items(&current_cart);
 
-                // update order discussion
-                // if there is room in the discussion, add this message
-                if details.discussion.len() < 10 {
-                    details.discussion.push(message_details);
-                    // update order_details: Mapping<Hash, Order>
-                    self.order_details.insert(order_id, &details);
+            // set up return structures
+            let mut subtotals = <Vec<SellerSubtotal>>::default();
+            let mut changes = <Vec<PriceChange>>::default();
+
+            for (item, number) in final_cart_items.iter() {
+                // every item that made it through sort_cart_items is still listed
+                let (seller, price, _) = match self.listing_details(*item) {
+                    Some(details) => details,
+                    None => continue,
+                };
+                let item_total = price.saturating_mul(*number);
+                // add this item to the seller's subtotal
+                if let Some(entry) = subtotals.iter_mut().find(|entry| entry.seller == seller) {
+                    entry.subtotal = entry.subtotal.saturating_add(item_total);
                 }
                 else {
-                    // if this discussion is full, send an error
-                    return Err(Error::StorageFull);
+                    subtotals.push(SellerSubtotal {
+                        seller,
+                        subtotal: item_total,
+                    });
+                }
+                // note any price change since the item was added to the cart
+                if let Some((_, price_when_added)) = current_cart.cart_prices.iter().find(|(id, _)| id == item) {
+                    if *price_when_added != price {
+                        changes.push(PriceChange {
+                            item_id: *item,
+                            price_when_added: *price_when_added,
+                            current_price: price,
+                        });
+                    }
                 }
-                
             }
-            else {
-                return Err(Error::NotYourOrder)
+
+            // package the results
+            CheckoutQuote {
+                buyer: caller,
+                amount_due: carttotal,
+                seller_subtotals: subtotals,
+                dropped_items,
+                price_changes: changes,
             }
-            Ok(())
         }
 
 
-        // 11 🟢 Update Buyer Account Settings
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> PLATFORM FEE MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+
+        // 46 🟢 Set Platform Fee (admin only)
+        // fee is in basis points, 100 = 1%, and applies to orders placed after the change
         #[ink(message)]
-        pub fn update_buyer_account_settings (&mut self, 
-            name: Vec<u8>,
-            location: Vec<u8>
+        pub fn set_platform_fee (&mut self,
+            platform_fee_bps: u128,
+            treasury: AccountId
         ) -> Result<(), Error> {
-            // if the inputs are too big, send an error
-            if name.len() > 100 || location.len() > 100 {
-                return Err(Error::DataTooLarge);
-            }
-            // set up the caller
             let caller = Self::env().caller();
-            // get the caller's Buyer Profile
-            // account_profile_buyer: Mapping<AccountId, BuyerProfile>
-            let mut profile = self.account_profile_buyer.get(caller).unwrap_or_default();
-            // update specific aspects of the BuyerProfile
-            profile.buyer_account = caller;
-            profile.buyer_name = name.clone();
-            profile.buyer_location = location.clone();
-            // update mappings
-            self.account_profile_buyer.insert(caller, &profile);
-
-            // EMIT EVENT
-            Self::env().emit_event(BuyerSettingsUpdated {
-                buyer_account: caller,
-                name: name,
-                location: location,
+            if caller != self.admin {
+                return Err(Error::NotAdmin);
+            }
+            // the platform fee and the protection slice together cannot pass 100%
+            if platform_fee_bps.saturating_add(self.protection_bps) > 10000 {
+                return Err(Error::FeeTooHigh);
+            }
+            self.platform_fee_bps = platform_fee_bps;
+            self.treasury = treasury;
+
+            // EMIT EVENT PlatformFeeUpdated
+            Self::env().emit_event(PlatformFeeUpdated {
+                admin: caller,
+                treasury,
+                platform_fee_bps,
             });
 
-
             Ok(())
         }
- 
 
-        // 12 🟢 Update Seller Account Settings
+
+        // 47 🟢 Withdraw Platform Fees (treasury only)
         #[ink(message)]
-        pub fn update_seller_account_settings (&mut self, 
-            name: Vec<u8>,
-            location: Vec<u8>,
-            description: Vec<u8>,
-            banner_url: Vec<u8>,
-            youtube_url: Vec<u8>,
-            external_link: Vec<u8>
-        ) -> Result<(), Error> {
-            if name.len() > 100 || location.len() > 100 || description.len() > 600 
-            || banner_url.len() > 200 || youtube_url.len() > 200 || external_link.len() > 200 {
-                return Err(Error::DataTooLarge);
-            }
-            // set up the caller
+        pub fn withdraw_platform_fees (&mut self) -> Result<(), Error> {
+            self.check_not_paused(false)?;
             let caller = Self::env().caller();
-            // get the caller's Seller Profile
-            // account_profile_seller: Mapping<AccountId, SellerProfile>
-            let mut profile = self.account_profile_seller.get(caller).unwrap_or_default();
-            // update specific aspects of the SellerProfile
-            profile.seller_account = caller;
-            profile.seller_name = name.clone();
-            profile.seller_location = location.clone();
-            profile.store_description = description.clone();
-            profile.banner_url = banner_url.clone();
-            profile.youtube_url = youtube_url.clone();
-            profile.external_link = external_link.clone();
-            // update mappings
-            self.account_profile_seller.insert(caller, &profile);
+            if caller != self.treasury {
+                return Err(Error::NotTreasury);
+            }
+            let amount = self.fees_available;
+            if amount > 0 {
+                self.fees_available = 0;
+                self.fees_withdrawn_total = self.fees_withdrawn_total.saturating_add(amount);
+                if self.env().transfer(caller, amount).is_err() {
+                    return Err(Error::PayoutFailed);
+                }
 
-            // EMIT EVENT
-            Self::env().emit_event(SellerSettingsUpdated {
-                seller_account: caller,
-                seller_name: name,
-                store_description: description,
-                seller_location: location,
-                banner_url: banner_url,
-                youtube_url: youtube_url,
-                external_link: external_link,
-            });
+                // EMIT EVENT PlatformFeesWithdrawn
+                Self::env().emit_event(PlatformFeesWithdrawn {
+                    treasury: caller,
+                    amount,
+                    timestamp: self.env().block_timestamp(),
+                });
+            }
 
             Ok(())
         }
 
 
-        // 13 🟢 Update Order Tracking Information 
+        // 48 🟢 View Platform Fees
+        // reports the fee settings, lifetime totals and the fees accrued on each recent day
         #[ink(message)]
-        pub fn update_order_tracking_information (&mut self, 
-            order_id: Hash,
-            tracking_update: Vec<u8>,
-            shipped: bool,
-            delivered: bool
-        ) -> Result<(), Error> {
-            // if the inputs are too big, send an error
-            if tracking_update.len() > 200 {
-                return Err(Error::DataTooLarge);
+        pub fn view_platform_fees (&self) -> PlatformFees {
+            let mut daily = <Vec<(u64, Balance)>>::default();
+            for day in self.fee_days.iter() {
+                daily.push((*day, self.fees_by_day.get(day).unwrap_or_default()));
             }
-            // set up the caller
-            let caller = Self::env().caller();
-            // make sure the caller is the seller on this order
-            // get the order details and compare to the seller
-            let mut details = self.order_details.get(order_id).unwrap_or_default();
-            if details.seller == caller {
-                // make sure the order is a physical product (not a service, or digital product)
-                let itemid = details.item_id;
-                if self.product_details.contains(itemid) {
-                    let item = self.product_details.get(itemid).unwrap_or_default();
-                    if !item.digital {
 
-                        details.tracking_info = tracking_update;
+            // package the results
+            PlatformFees {
+                treasury: self.treasury,
+                platform_fee_bps: self.platform_fee_bps,
+                total_accrued: self.fees_accrued_total,
+                total_withdrawn: self.fees_withdrawn_total,
+                available: self.fees_available,
+                daily_fees: daily
+            }
+        }
 
-                        if details.order_status == 1 {
-                            // seller can mark delivered, or can leave it as shipped
-                            if delivered {
-                                details.order_status = 2;
-                                details.time_delivered = self.env().block_timestamp();
-                                // update order_details: Mapping<Hash, Order> 
-                                self.order_details.insert(order_id, &details);
 
-                                // move this order from orders_1shipped to orders_2delivered 
-                                let mut shipped = self.account_seller_orders_1shipped.get(caller).unwrap_or_default();
-                                let mut delivered = self.account_seller_orders_2delivered.get(caller).unwrap_or_default();
-                                // if the seller's delivered list is full, kick out the oldest
-                                if delivered.hashvector.len() > 69 {
-                                    delivered.hashvector.remove(0);
-                                }
-                                shipped.hashvector.retain(|value| *value != order_id);
-                                delivered.hashvector.push(order_id);
-                                // update the maps
-                                self.account_seller_orders_2delivered.insert(caller, &delivered);
-                                self.account_seller_orders_1shipped.insert(caller, &shipped);
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> WITHDRAWAL MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
-                                // EMIT EVENT OrderDelivered
-                                Self::env().emit_event(OrderDelivered {
-                                    seller: caller,
-                                    buyer: details.buyer,
-                                    order_id: details.order_id,
-                                    order_status: 2,
-                                    time_delivered: self.env().block_timestamp(),
-                                });
 
-                                // update Buyer profile
-                                // account_profile_buyer: Mapping<AccountId, BuyerProfile>
-                                let buyer = details.buyer;
-                                let mut buyerprofile = self.account_profile_buyer.get(buyer).unwrap_or_default();
-                                buyerprofile.total_delivered = buyerprofile.total_delivered.saturating_add(1);
-                                self.account_profile_buyer.insert(buyer, &buyerprofile);
+        // 49 🟢 Withdraw
+        // sellers, buyers and zeno affiliates collect everything credited to them
+        #[ink(message)]
+        pub fn withdraw (&mut self) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            let amount = self.claimable_balance.get(caller).unwrap_or_default();
+            if amount == 0 {
+                return Err(Error::NothingToWithdraw);
+            }
+            // clear the balance before paying out
+            self.claimable_balance.remove(caller);
+            if self.env().transfer(caller, amount).is_err() {
+                return Err(Error::PayoutFailed);
+            }
 
-                                // update Seller profile
-                                // account_profile_seller: Mapping<AccountId, SellerProfile>
-                                let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
-                                sellerprofile.total_delivered = sellerprofile.total_delivered.saturating_add(1);
-                                self.account_profile_seller.insert(caller, &sellerprofile);
-                                
-                            }
-                        }
+            // EMIT EVENT BalanceWithdrawn
+            Self::env().emit_event(BalanceWithdrawn {
+                account: caller,
+                amount,
+                timestamp: self.env().block_timestamp(),
+            });
 
-                        if details.order_status == 0 {
-                            // seller can mark shipped but not delivered
-                            if shipped {
-                                details.order_status = 1;
-                                // update order_details: Mapping<Hash, Order> 
-                                self.order_details.insert(order_id, &details);
+            Ok(())
+        }
 
-                                // move this order from orders_0awaiting to orders_1shipped
-                                let mut shipped = self.account_seller_orders_1shipped.get(caller).unwrap_or_default();
-                                let mut awaiting = self.account_seller_orders_0awaiting.get(caller).unwrap_or_default();
-                                // if the seller's delivered list is full, kick out the oldest
-                                if shipped.hashvector.len() > 69 {
-                                    shipped.hashvector.remove(0);
-                                }
-                                awaiting.hashvector.retain(|value| *value != order_id);
-                                shipped.hashvector.push(order_id);
-                                // update the maps
-                                self.account_seller_orders_0awaiting.insert(caller, &awaiting);
-                                self.account_seller_orders_1shipped.insert(caller, &shipped);
 
-                                // update Seller profile
-                                // account_profile_seller: Mapping<AccountId, SellerProfile>
-                                let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
-                                sellerprofile.awaiting = sellerprofile.awaiting.saturating_sub(1);
-                                self.account_profile_seller.insert(caller, &sellerprofile);
+        // 50 🟢 View Claimable Balance
+        #[ink(message)]
+        pub fn view_claimable_balance (&self, account: AccountId) -> Balance {
+            self.claimable_balance.get(account).unwrap_or_default()
+        }
 
-                                // EMIT EVENT OrderShipped
-                                Self::env().emit_event(OrderShipped {
-                                    seller: caller,
-                                    buyer: details.buyer,
-                                    order_id: details.order_id,
-                                    order_status: 1,
-                                });
 
-                                // calculate payments to seller and zeno buyers
-                                let seller = details.seller;
-                                let total_price: Balance = details.total_order_price;
-                                let zeno_total: Balance = details.zeno_total;
-                                let zeno_buyers = item.zeno_buyers;
-                                let seller_payout: Balance = total_price.saturating_sub(zeno_total);
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> ZENO INCENTIVE MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
-                                // pay the seller 
-                                if self.env().transfer(seller, seller_payout).is_err() {
-                                    return Err(Error::PayoutFailed);
-                                }
 
-                                // If the zeno_total is not zero, initiate the zeno payouts
-                                if zeno_total > 0 {
-                                    // pay all zeno buyers
-                                    let mut remainder: Balance = zeno_total;
-                                    for (n, affiliate) in zeno_buyers.iter().enumerate() {
-                                        // n needs to be a u128, convert it and add 1
-                                        let m: u32 = n.try_into().unwrap();
-                                        let p: u32 = m.saturating_add(1);
-                                        // let fraction: u32 = 2u32.saturating_pow(p);
-                                        // let payment: Option<Balance> = Some(0);
-                                        let payment: Balance = zeno_total.checked_div(2u128.saturating_pow(p)).unwrap();
-                                        if self.env().transfer(*affiliate, payment).is_err() {
-                                            return Err(Error::PayoutFailed);
-                                        }
-                                        remainder = remainder.saturating_sub(payment);
-                                    }
-                                    // pay the seller any remainder from the zeno payouts
-                                    if remainder > 0 {
-                                        if self.env().transfer(seller, remainder).is_err() {
-                                         return Err(Error::PayoutFailed);
-                                        }
-                                    }
-                                    
-                                }
-                                
-                            }
-                        }
+        // 51 🟢 Update Zeno Settings
+        // max_buyers must be 1-100, curve: 0 = halving, 1 = linear, 2 = equal split
+        // once any zeno slot is taken, max_buyers and curve are locked so earnings
+        // already promised to zeno buyers cannot be changed
+        #[ink(message)]
+        pub fn update_zeno_settings (&mut self,
+            item_id: Hash,
+            max_buyers: u128,
+            curve: u8,
+            unique_buyers: bool
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            // set up the caller
+            let caller = Self::env().caller();
+            if max_buyers == 0 || max_buyers > 100 || curve > 2 {
+                return Err(Error::InvalidZenoSettings);
+            }
 
-                    }
-                    else {
-                        return Err(Error::NotAPhysicalProduct)
-                    }
+            // is this your product or service?
+            let seller_products = self.account_seller_products.get(caller).unwrap_or_default();
+            let seller_services = self.account_seller_services.get(caller).unwrap_or_default();
+            if seller_products.hashvector.contains(&item_id) {
+                let mut details = self.product_details.get(item_id).unwrap_or_default();
+                if !details.zeno_buyers.is_empty()
+                && (max_buyers != details.zeno_max_buyers || curve != details.zeno_curve) {
+                    return Err(Error::InvalidZenoSettings);
                 }
-                else {
-                    return Err(Error::NotAPhysicalProduct)
-                }                
+                details.zeno_max_buyers = max_buyers;
+                details.zeno_curve = curve;
+                details.zeno_unique_buyers = unique_buyers;
+                self.product_details.insert(item_id, &details);
+            }
+            else if seller_services.hashvector.contains(&item_id) {
+                let mut details = self.service_details.get(item_id).unwrap_or_default();
+                if !details.zeno_buyers.is_empty()
+                && (max_buyers != details.zeno_max_buyers || curve != details.zeno_curve) {
+                    return Err(Error::InvalidZenoSettings);
+                }
+                details.zeno_max_buyers = max_buyers;
+                details.zeno_curve = curve;
+                details.zeno_unique_buyers = unique_buyers;
+                self.service_details.insert(item_id, &details);
             }
             else {
-                return Err(Error::NotYourOrder)
+                return Err(Error::NotYourProduct);
             }
+
+            // EMIT EVENT ZenoSettingsUpdated
+            Self::env().emit_event(ZenoSettingsUpdated {
+                item_id,
+                seller_account: caller,
+                zeno_max_buyers: max_buyers,
+                zeno_curve: curve,
+                zeno_unique_buyers: unique_buyers,
+            });
+
             Ok(())
         }
 
 
-        // 14 🟢 Refuse An Order
+        // 52 🟢 View Zeno Projection
+        // shows what each zeno slot on a listing earns from one future sale at the current price
         #[ink(message)]
-        pub fn refuse_an_order (&mut self, 
-            order_id: Hash
-        ) -> Result<(), Error> {
-            // set up the caller
+        pub fn view_zeno_projection (&self, item_id: Hash) -> ZenoProjection {
+            let mut projection = ZenoProjection {
+                item_id,
+                ..Default::default()
+            };
+            if let Some(details) = self.product_details.get(item_id) {
+                projection.price = details.price;
+                projection.zeno_percent = details.zeno_percent;
+                projection.zeno_max_buyers = details.zeno_max_buyers;
+                projection.zeno_curve = details.zeno_curve;
+                projection.zeno_unique_buyers = details.zeno_unique_buyers;
+                projection.filled_slots = details.zeno_buyers.len().try_into().unwrap();
+            }
+            else if let Some(details) = self.service_details.get(item_id) {
+                projection.price = details.price;
+                projection.zeno_percent = details.zeno_percent;
+                projection.zeno_max_buyers = details.zeno_max_buyers;
+                projection.zeno_curve = details.zeno_curve;
+                projection.zeno_unique_buyers = details.zeno_unique_buyers;
+                projection.filled_slots = details.zeno_buyers.len().try_into().unwrap();
+            }
+            let zeno_total: Balance = projection.price.saturating_mul(projection.zeno_percent).saturating_div(100);
+            projection.slot_earnings = Self::zeno_slot_shares(zeno_total, projection.zeno_max_buyers, projection.zeno_curve);
+
+            projection
+        }
+
+
+        // 53 🟢 View My Zeno Earnings
+        // lists every item where the caller holds a zeno slot, their slot positions
+        // and what they have earned from each item so far
+        #[ink(message)]
+        pub fn view_my_zeno_earnings (&self) -> ViewZenoEarnings {
+            // set the caller
             let caller = Self::env().caller();
-            // make sure the caller is the seller on this order
-            let mut details = self.order_details.get(order_id).unwrap_or_default();
-            if details.seller == caller {
-                // you can only refuse an order that has not yet shipped. Status must be 0.
-                if details.order_status == 0 {
-                    
-                    // issue a refund to the buyer for this order
-                    let buyer = details.buyer;
-                    let refund: Balance = details.total_order_price;
-                    if self.env().transfer(buyer, refund).is_err() {
-                        return Err(Error::PayoutFailed);
+            // set up return structures
+            let mut earnings = <Vec<ZenoEarning>>::default();
+
+            // get the caller's zeno items from account_zeno_items: Mapping<AccountId, HashVector>
+            let item_ids = self.account_zeno_items.get(caller).unwrap_or_default();
+            for id in item_ids.hashvector.iter() {
+                let mut title = <Vec<u8>>::default();
+                if let Some(details) = self.product_details.get(id) {
+                    title = details.title;
+                }
+                else if let Some(details) = self.service_details.get(id) {
+                    title = details.title;
+                }
+                let (zeno_buyers, _, _) = self.listing_zeno(*id);
+                let mut slots = <Vec<u128>>::default();
+                for (n, affiliate) in zeno_buyers.iter().enumerate() {
+                    if *affiliate == caller {
+                        slots.push(n.saturating_add(1).try_into().unwrap());
                     }
+                }
+                earnings.push(ZenoEarning {
+                    item_id: *id,
+                    title,
+                    slots,
+                    earned: self.account_zeno_item_earnings.get((caller, *id)).unwrap_or_default(),
+                });
+            }
 
-                    // update order_details: Mapping<Hash, Order>
-                    details.order_status = 5;
-                    self.order_details.insert(order_id, &details);
+            // package the results
+            ViewZenoEarnings {
+                affiliate: caller,
+                lifetime_total: self.account_zeno_earnings_total.get(caller).unwrap_or_default(),
+                items: earnings
+            }
+        }
 
-                    // move the order from orders_0awaiting to orders_5refused
-                    let mut refused = self.account_seller_orders_5refused.get(caller).unwrap_or_default();
-                    let mut awaiting = self.account_seller_orders_0awaiting.get(caller).unwrap_or_default();
-                    // if the seller's refused list is full, kick out the oldest
-                    if refused.hashvector.len() > 69 {
-                        refused.hashvector.remove(0);
-                    }
-                    awaiting.hashvector.retain(|value| *value != order_id);
-                    refused.hashvector.push(order_id);
-                    // update the maps
-                    self.account_seller_orders_0awaiting.insert(caller, &awaiting);
-                    self.account_seller_orders_5refused.insert(caller, &refused);
 
-                    // update Buyer profile
-                    // account_profile_buyer: Mapping<AccountId, BuyerProfile>
-                    let mut buyerprofile = self.account_profile_buyer.get(buyer).unwrap_or_default();
-                    buyerprofile.total_refused = buyerprofile.total_refused.saturating_add(1);
-                    self.account_profile_buyer.insert(buyer, &buyerprofile);
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> REFUND OFFER MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
-                    // update Seller profile
-                    // account_profile_seller: Mapping<AccountId, SellerProfile>
-                    let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
-                    sellerprofile.total_refused = sellerprofile.total_refused.saturating_add(1);
-                    sellerprofile.awaiting = sellerprofile.awaiting.saturating_sub(1);
-                    self.account_profile_seller.insert(caller, &sellerprofile);
 
-                    // EMIT EVENT OrderRefused 
-                    Self::env().emit_event(OrderRefused {
-                        seller: caller,
-                        buyer: details.buyer,
-                        order_id: details.order_id,
-                        order_status: 5,
-                    });
+        // 54 🟢 Accept Refund Offer
+        // the buyer accepts a partial refund, which resolves the problem
+        #[ink(message)]
+        pub fn accept_refund_offer (&mut self,
+            order_id: Hash
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.buyer != caller {
+                return Err(Error::NotYourOrder);
+            }
+            if details.order_status != OrderStatus::Problem || details.refund_offered == 0 {
+                return Err(Error::NoRefundOffer);
+            }
+            let amount = details.refund_offered;
+            details.refund_offered = 0;
+            self.refund_from_escrow(&details, amount);
+            self.finish_refund(details, amount, Resolution::Refunded, caller)?;
 
-                }
-                else {
-                    return Err(Error::CannotRefuse)
-                }
+            Ok(())
+        }
+
+
+        // 55 🟢 Decline Refund Offer
+        // the buyer turns down a partial refund, the offer goes back to the seller
+        // and the problem stays open
+        #[ink(message)]
+        pub fn decline_refund_offer (&mut self,
+            order_id: Hash
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.buyer != caller {
+                return Err(Error::NotYourOrder);
             }
-            else {
-                return Err(Error::NotYourOrder)
+            if details.order_status != OrderStatus::Problem || details.refund_offered == 0 {
+                return Err(Error::NoRefundOffer);
             }
+            let amount = details.refund_offered;
+            self.return_refund_offer(&mut details);
+            self.order_details.insert(order_id, &details);
+
+            // EMIT EVENT RefundOfferDeclined
+            Self::env().emit_event(RefundOfferDeclined {
+                seller: details.seller,
+                buyer: caller,
+                order_id,
+                amount,
+            });
+
             Ok(())
         }
 
 
-        // 15 🟢 Issue Refund
-        // note that refunds are issued as a resolution to a problem
-        // seller inputs the refund amount from their own account since payouts already happened
-        #[ink(message, payable)]
-        pub fn issue_refund (&mut self, 
-            order_id: Hash,
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> CANCELLATION MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+
+        // 56 🟢 Cancel Order
+        // the buyer backs out of an order the seller has not shipped yet
+        // the full price is refunded from escrow and the inventory goes back on the shelf
+        #[ink(message)]
+        pub fn cancel_order (&mut self,
+            order_id: Hash
         ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
             // set up the caller
             let caller = Self::env().caller();
-            // make sure the caller is the seller on this order
+            // make sure the caller is the buyer on this order
             let mut details = self.order_details.get(order_id).unwrap_or_default();
-            if details.seller == caller {
-                // you can only refund an order that has a problem and no resolution. 
-                // Status must be 4 and resolution must be 0.
-                
-                if details.order_status == 4 && details.resolution == 0 {
+            if details.buyer != caller {
+                return Err(Error::NotYourOrder);
+            }
+            // you can only cancel an order that has not yet shipped. Status must be Awaiting.
+            if details.order_status != OrderStatus::Awaiting {
+                return Err(Error::CannotCancel);
+            }
+            // if the seller set a cancellation window, make sure it is still open
+            let seller = details.seller;
+            let sellerprofile = self.account_profile_seller.get(seller).unwrap_or_default();
+            let now = self.env().block_timestamp();
+            if sellerprofile.cancel_window > 0
+            && now > details.order_timestamp.saturating_add(sellerprofile.cancel_window) {
+                return Err(Error::CannotCancel);
+            }
 
-                    // COLLECT PAYMENT FROM THE CALLER
-                    // the 'payable' tag on this message allows the user to send any amount
-                    let refund: Balance = self.env().transferred_value();
-                    
-                    // issue a refund to the buyer for this order
-                    let buyer = details.buyer;
-                    if self.env().transfer(buyer, refund).is_err() {
-                        return Err(Error::PayoutFailed);
-                    }
+            // credit a refund to the buyer and clear the escrow for this order
+            let refund: Balance = details.total_order_price;
+            self.credit_account(caller, order_id, refund, CreditReason::Refund);
+            self.remove_from_escrow(seller, order_id);
 
-                    // update order_details: Mapping<Hash, Order>
-                    details.resolution = 1;
-                    details.order_status = 3;
-                    self.order_details.insert(order_id, &details);
+            // put the items back in inventory
+            self.restore_inventory(&details, 3);
 
-                    // move the order from orders_4problem to orders_3resolved
-                    let mut problem = self.account_seller_orders_4problem.get(caller).unwrap_or_default();
-                    let mut resolved = self.account_seller_orders_3resolved.get(caller).unwrap_or_default();
-                    // if the seller's refused list is full, kick out the oldest
-                    if resolved.hashvector.len() > 69 {
-                        resolved.hashvector.remove(0);
-                    }
-                    problem.hashvector.retain(|value| *value != order_id);
-                    resolved.hashvector.push(order_id);
-                    // update the maps
-                    self.account_seller_orders_4problem.insert(caller, &problem);
-                    self.account_seller_orders_3resolved.insert(caller, &resolved);
+            // update order_details: Mapping<Hash, Order>
+            self.transition(&mut details, OrderStatus::Cancelled, caller, b"cancelled by buyer")?;
+            self.order_details.insert(order_id, &details);
 
-                    // update Buyer profile
-                    // account_profile_buyer: Mapping<AccountId, BuyerProfile>
-                    let mut buyerprofile = self.account_profile_buyer.get(buyer).unwrap_or_default();
-                    buyerprofile.total_resolved = buyerprofile.total_resolved.saturating_add(1);
-                    self.account_profile_buyer.insert(buyer, &buyerprofile);
+            // move the order from orders_0awaiting to orders_5refused
+            let mut refused = self.account_seller_orders_5refused.get(seller).unwrap_or_default();
+            let mut awaiting = self.account_seller_orders_0awaiting.get(seller).unwrap_or_default();
+            // if the seller's refused list is full, kick out the oldest
+            if refused.hashvector.len() > 69 {
+                refused.hashvector.remove(0);
+            }
+            awaiting.hashvector.retain(|value| *value != order_id);
+            refused.hashvector.push(order_id);
+            // update the maps
+            self.account_seller_orders_0awaiting.insert(seller, &awaiting);
+            self.account_seller_orders_5refused.insert(seller, &refused);
 
-                    // update Seller profile
-                    // account_profile_seller: Mapping<AccountId, SellerProfile>
-                    let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
-                    sellerprofile.total_resolved = sellerprofile.total_resolved.saturating_add(1);
-                    self.account_profile_seller.insert(caller, &sellerprofile);
+            // update Seller profile
+            // account_profile_seller: Mapping<AccountId, SellerProfile>
+            let mut sellerprofile = sellerprofile;
+            sellerprofile.awaiting = sellerprofile.awaiting.saturating_sub(1);
+            self.account_profile_seller.insert(seller, &sellerprofile);
 
-                    // EMIT EVENT OrderRefunded
-                    Self::env().emit_event(OrderRefunded {
-                        seller: caller,
-                        buyer: details.buyer,
-                        order_id: details.order_id,
-                        order_status: 3,
-                        problem: details.problem,
-                        resolution: 1,
-                    });
+            // EMIT EVENT OrderCancelled
+            Self::env().emit_event(OrderCancelled {
+                seller,
+                buyer: caller,
+                order_id,
+                order_status: OrderStatus::Cancelled,
+                refund,
+            });
 
-                }
-                else {
-                    return Err(Error::CannotResolve)
-                }
+            Ok(())
+        }
+
+
+        // 57 🟢 Set Cancellation Window
+        // the seller sets how long (ms) after ordering buyers may cancel, 0 = any time before shipping
+        #[ink(message)]
+        pub fn set_cancellation_window (&mut self,
+            cancel_window: u64
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            let mut profile = self.account_profile_seller.get(caller).unwrap_or_default();
+            profile.seller_account = caller;
+            profile.cancel_window = cancel_window;
+            self.account_profile_seller.insert(caller, &profile);
+
+            // EMIT EVENT
+            Self::env().emit_event(CancellationWindowUpdated {
+                seller_account: caller,
+                cancel_window,
+            });
+
+            Ok(())
+        }
+
+
+        // 58 🟢 Restock Returned Order
+        // after refunding an order, the seller confirms the items came back
+        // and puts them back in inventory (once per order)
+        #[ink(message)]
+        pub fn restock_returned_order (&mut self,
+            order_id: Hash
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.seller != caller {
+                return Err(Error::NotYourOrder);
             }
-            else {
-                return Err(Error::NotYourOrder)
+            if details.resolution != Resolution::Refunded || details.restocked {
+                return Err(Error::CannotRestock);
             }
-            
+            details.restocked = true;
+            self.order_details.insert(order_id, &details);
+            self.restore_inventory(&details, 4);
+
             Ok(())
         }
-        
-        
-        // 16 🟢 Issue Replacement
+
+
+        // 59 🟢 Refund Unshipped Order
+        // if the seller has not shipped by the order's handling deadline, anyone can
+        // call this to refund the buyer and move the order to the seller's refused list
         #[ink(message)]
-        pub fn issue_replacement (&mut self, 
-            order_id: Hash,
-            tracking: Vec<u8>
+        pub fn refund_unshipped_order (&mut self,
+            order_id: Hash
         ) -> Result<(), Error> {
-            // make sure the tracking info is not too long
-            if tracking.len() > 200 {
-                return Err(Error::DataTooLarge);
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            // the order must still be awaiting shipment. Status must be Awaiting.
+            if details.order_status != OrderStatus::Awaiting {
+                return Err(Error::CannotRefuse);
+            }
+            let now = self.env().block_timestamp();
+            if details.handling_deadline == 0 || now <= details.handling_deadline {
+                return Err(Error::HandlingTimeNotExpired);
+            }
+
+            // credit a refund to the buyer and clear the escrow for this order
+            let seller = details.seller;
+            let buyer = details.buyer;
+            let refund: Balance = details.total_order_price;
+            self.credit_account(buyer, order_id, refund, CreditReason::Refund);
+            self.remove_from_escrow(seller, order_id);
+
+            // put the items back in inventory
+            self.restore_inventory(&details, 2);
+
+            // update order_details: Mapping<Hash, Order>
+            self.transition(&mut details, OrderStatus::Refused, caller, b"handling deadline passed")?;
+            self.order_details.insert(order_id, &details);
+
+            // move the order from orders_0awaiting to orders_5refused
+            let mut refused = self.account_seller_orders_5refused.get(seller).unwrap_or_default();
+            let mut awaiting = self.account_seller_orders_0awaiting.get(seller).unwrap_or_default();
+            // if the seller's refused list is full, kick out the oldest
+            if refused.hashvector.len() > 69 {
+                refused.hashvector.remove(0);
+            }
+            awaiting.hashvector.retain(|value| *value != order_id);
+            refused.hashvector.push(order_id);
+            // update the maps
+            self.account_seller_orders_0awaiting.insert(seller, &awaiting);
+            self.account_seller_orders_5refused.insert(seller, &refused);
+
+            // update Buyer profile
+            // account_profile_buyer: Mapping<AccountId, BuyerProfile>
+            let mut buyerprofile = self.account_profile_buyer.get(buyer).unwrap_or_default();
+            buyerprofile.total_refused = buyerprofile.total_refused.saturating_add(1);
+            self.account_profile_buyer.insert(buyer, &buyerprofile);
+
+            // update Seller profile
+            // account_profile_seller: Mapping<AccountId, SellerProfile>
+            let mut sellerprofile = self.account_profile_seller.get(seller).unwrap_or_default();
+            sellerprofile.total_refused = sellerprofile.total_refused.saturating_add(1);
+            sellerprofile.total_missed_handling = sellerprofile.total_missed_handling.saturating_add(1);
+            sellerprofile.awaiting = sellerprofile.awaiting.saturating_sub(1);
+            self.account_profile_seller.insert(seller, &sellerprofile);
+
+            // a bonded seller who abandons an order also pays the buyer the admin set penalty
+            let penalty: Balance = details.total_order_price.saturating_mul(self.abandon_penalty_bps).saturating_div(10000);
+            if penalty > 0 {
+                let compensation = self.slash_bond(seller, order_id, penalty);
+                self.credit_account(buyer, order_id, compensation, CreditReason::BondCompensation);
             }
-            // set up the caller
-            let caller = Self::env().caller();
 
-            // make sure the caller is the seller on this order
-            let mut details = self.order_details.get(order_id).unwrap_or_default();
-            if details.seller == caller {
-                // you can only replace an order that has a problem. Status must be 4.
-                if details.order_status == 4 && details.resolution == 0 {
-                    
-                    // update order_details: Mapping<Hash, Order>
-                    // update the shipping details with the replacement item
-                    details.tracking_info = tracking;
-                    // update the resolution code
-                    details.resolution = 2;
-                    // update the status to resolved
-                    details.order_status = 3;
-                    // update the mapping
-                    self.order_details.insert(order_id, &details);
+            // EMIT EVENT OrderHandlingExpired
+            Self::env().emit_event(OrderHandlingExpired {
+                seller,
+                buyer,
+                order_id,
+                order_status: OrderStatus::Refused,
+                refund,
+                called_by: caller,
+            });
 
-                    // move the order from orders_4problem to orders_3resolved
-                    let mut problem = self.account_seller_orders_4problem.get(caller).unwrap_or_default();
-                    let mut resolved = self.account_seller_orders_3resolved.get(caller).unwrap_or_default();
-                    // if the seller's refused list is full, kick out the oldest
-                    if resolved.hashvector.len() > 69 {
-                        resolved.hashvector.remove(0);
+            Ok(())
+        }
+
+
+        // 60 🟢 Deliver Overdue Orders
+        // anyone can mark a seller's shipped orders delivered once their delivery deadline
+        // has passed, which starts the buyer's window to report a problem
+        #[ink(message)]
+        pub fn deliver_overdue_orders (&mut self,
+            seller: AccountId
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            let rightnow = self.env().block_timestamp();
+            // get the seller's shipped orders account_seller_orders_1shipped: Mapping<AccountId, HashVector>
+            let mut shipped = self.account_seller_orders_1shipped.get(seller).unwrap_or_default();
+            let mut delivered = self.account_seller_orders_2delivered.get(seller).unwrap_or_default();
+            let mut count: u128 = 0;
+            for id in shipped.hashvector.clone().iter() {
+                let mut details = self.order_details.get(id).unwrap_or_default();
+                if details.order_status == OrderStatus::Shipped && details.delivery_deadline > 0 && rightnow > details.delivery_deadline {
+                    self.transition(&mut details, OrderStatus::Delivered, caller, b"delivery deadline passed")?;
+                    details.time_delivered = rightnow;
+                    self.order_details.insert(id, &details);
+
+                    // move this order from orders_1shipped to orders_2delivered
+                    // if the seller's delivered list is full, kick out the oldest
+                    if delivered.hashvector.len() > 69 {
+                        delivered.hashvector.remove(0);
                     }
-                    problem.hashvector.retain(|value| *value != order_id);
-                    resolved.hashvector.push(order_id);
-                    // update the maps
-                    self.account_seller_orders_4problem.insert(caller, &problem);
-                    self.account_seller_orders_3resolved.insert(caller, &resolved);
+                    shipped.hashvector.retain(|value| value != id);
+                    delivered.hashvector.push(*id);
 
                     // update Buyer profile
                     let buyer = details.buyer;
-                    // account_profile_buyer: Mapping<AccountId, BuyerProfile>
                     let mut buyerprofile = self.account_profile_buyer.get(buyer).unwrap_or_default();
-                    buyerprofile.total_resolved = buyerprofile.total_resolved.saturating_add(1);
+                    buyerprofile.total_delivered = buyerprofile.total_delivered.saturating_add(1);
                     self.account_profile_buyer.insert(buyer, &buyerprofile);
+                    count = count.saturating_add(1);
+
+                    // EMIT EVENT OrderDelivered
+                    Self::env().emit_event(OrderDelivered {
+                        seller,
+                        buyer,
+                        order_id: *id,
+                        order_status: OrderStatus::Delivered,
+                        time_delivered: rightnow,
+                    });
+                }
+            }
+            // update the maps
+            self.account_seller_orders_1shipped.insert(seller, &shipped);
+            self.account_seller_orders_2delivered.insert(seller, &delivered);
+
+            // update Seller profile
+            if count > 0 {
+                let mut sellerprofile = self.account_profile_seller.get(seller).unwrap_or_default();
+                sellerprofile.total_delivered = sellerprofile.total_delivered.saturating_add(count);
+                self.account_profile_seller.insert(seller, &sellerprofile);
+            }
 
-                    // update Seller profile
-                    // account_profile_seller: Mapping<AccountId, SellerProfile>
-                    let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
-                    sellerprofile.total_resolved = sellerprofile.total_resolved.saturating_add(1);
-                    self.account_profile_seller.insert(caller, &sellerprofile);
+            Ok(())
+        }
 
-                    // EMIT EVENT OrderReplaced
-                    Self::env().emit_event(OrderReplaced {
-                        seller: caller,
-                        buyer: details.buyer,
-                        order_id: details.order_id,
-                        order_status: 3,
-                        problem: details.problem,
-                        resolution: 2,
-                    });
 
-                }
-                else {
-                    return Err(Error::CannotResolve)
-                }
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> ARBITRATION MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+
+        // 61 🟢 Add Arbiter
+        // admin only, up to 50 arbiters
+        #[ink(message)]
+        pub fn add_arbiter (&mut self,
+            arbiter: AccountId
+        ) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if caller != self.admin {
+                return Err(Error::NotAdmin);
             }
-            else {
-                return Err(Error::NotYourOrder)
+            if !self.arbiters.contains(&arbiter) {
+                if self.arbiters.len() > 49 {
+                    return Err(Error::StorageFull);
+                }
+                self.arbiters.push(arbiter);
             }
-            
+
+            // EMIT EVENT ArbiterUpdated
+            Self::env().emit_event(ArbiterUpdated {
+                admin: caller,
+                arbiter,
+                added: true,
+            });
+
             Ok(())
         }
-       
-       
-        // 17 🟢 Deny Resolution Request
+
+
+        // 62 🟢 Remove Arbiter
+        // admin only
         #[ink(message)]
-        pub fn deny_resolution_request (&mut self, 
-            order_id: Hash,
+        pub fn remove_arbiter (&mut self,
+            arbiter: AccountId
         ) -> Result<(), Error> {
-            // set up the caller
             let caller = Self::env().caller();
-            // make sure the caller is the seller on this order
-            let mut details = self.order_details.get(order_id).unwrap_or_default();
-            if details.seller == caller {
-                // you can only deny resolution on an order that has a problem. Status must be 4.
-                if details.order_status == 4 && details.resolution == 0 {
-                    
-                    // update order_details: Mapping<Hash, Order>
-                    details.resolution = 3;
-                    details.order_status = 3;
-                    self.order_details.insert(order_id, &details);
+            if caller != self.admin {
+                return Err(Error::NotAdmin);
+            }
+            self.arbiters.retain(|value| *value != arbiter);
 
-                    // move the order from orders_4problem to orders_3resolved
-                    let mut problem = self.account_seller_orders_4problem.get(caller).unwrap_or_default();
-                    let mut resolved = self.account_seller_orders_3resolved.get(caller).unwrap_or_default();
-                    // if the seller's refused list is full, kick out the oldest
-                    if resolved.hashvector.len() > 69 {
-                        resolved.hashvector.remove(0);
-                    }
-                    problem.hashvector.retain(|value| *value != order_id);
-                    resolved.hashvector.push(order_id);
-                    // update the maps
-                    self.account_seller_orders_4problem.insert(caller, &problem);
-                    self.account_seller_orders_3resolved.insert(caller, &resolved);
+            // EMIT EVENT ArbiterUpdated
+            Self::env().emit_event(ArbiterUpdated {
+                admin: caller,
+                arbiter,
+                added: false,
+            });
 
-                    // EMIT EVENT OrderResolutionDenied
-                    Self::env().emit_event(OrderResolutionDenied {
-                        seller: caller,
-                        buyer: details.buyer,
-                        order_id: details.order_id,
-                        order_status: 3,
-                        problem: details.problem,
-                        resolution: 3,
-                    });
+            Ok(())
+        }
 
-                }
-                else {
-                    return Err(Error::CannotResolve)
-                }
+
+        // 63 🟢 View Arbiters
+        #[ink(message)]
+        pub fn view_arbiters (&self) -> Vec<AccountId> {
+            self.arbiters.clone()
+        }
+
+
+        // 64 🟢 Escalate Dispute
+        // the buyer appeals a seller's denial to the arbiters within 24 hours of the denial
+        // any escrowed payment stays locked until an arbiter rules
+        #[ink(message)]
+        pub fn escalate_dispute (&mut self,
+            order_id: Hash
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            let now = self.env().block_timestamp();
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.buyer != caller {
+                return Err(Error::NotYourOrder);
             }
-            else {
-                return Err(Error::NotYourOrder)
+            if details.resolution != Resolution::Denied || details.arbitration != Arbitration::None
+            || now > details.time_denied.saturating_add(ESCALATION_WINDOW) {
+                return Err(Error::CannotEscalate);
             }
-            
+            // a buyer waiting on or paid by the protection pool cannot also ask an arbiter for a refund
+            if self.protection_claims.get(order_id).is_some_and(|claim| claim.status != ClaimStatus::Rejected) {
+                return Err(Error::CannotEscalate);
+            }
+            // keep up to 200 open disputes for the arbiters
+            if self.open_disputes.len() > 199 {
+                return Err(Error::StorageFull);
+            }
+            self.open_disputes.push(order_id);
+
+            // update order_details: Mapping<Hash, Order>
+            details.arbitration = Arbitration::Escalated;
+            self.add_timeline_entry(&mut details, caller, b"escalated to arbitration");
+            self.order_details.insert(order_id, &details);
+
+            // update Buyer profile
+            let mut buyerprofile = self.account_profile_buyer.get(caller).unwrap_or_default();
+            buyerprofile.total_arbitrations = buyerprofile.total_arbitrations.saturating_add(1);
+            self.account_profile_buyer.insert(caller, &buyerprofile);
+
+            // update Seller profile
+            let seller = details.seller;
+            let mut sellerprofile = self.account_profile_seller.get(seller).unwrap_or_default();
+            sellerprofile.total_arbitrations = sellerprofile.total_arbitrations.saturating_add(1);
+            self.account_profile_seller.insert(seller, &sellerprofile);
+
+            // EMIT EVENT DisputeEscalated
+            Self::env().emit_event(DisputeEscalated {
+                seller,
+                buyer: caller,
+                order_id,
+                timestamp: now,
+            });
+
             Ok(())
         }
-        
-        
-        // 18 🟢 Message The Buyer
+
+
+        // 65 🟢 View Open Disputes
+        // escalated orders waiting on an arbiter's ruling
         #[ink(message)]
-        pub fn message_the_buyer (&mut self, 
+        pub fn view_open_disputes (&self) -> Vec<Order> {
+            let mut disputes = <Vec<Order>>::default();
+            for id in self.open_disputes.iter() {
+                let details = self.order_details.get(id).unwrap_or_default();
+                disputes.push(details);
+            }
+            disputes
+        }
+
+
+        // 66 🟢 Arbiter Rule Refund
+        // an arbiter overturns the seller's denial and refunds the buyer, first out of
+        // any escrow held for the order, then the seller's bond, then the seller's claimable balance
+        #[ink(message)]
+        pub fn arbiter_rule_refund (&mut self,
             order_id: Hash,
-            photo_or_youtube_url: Vec<u8>,
-            message: Vec<u8>,
+            refund: Balance
         ) -> Result<(), Error> {
-            // if the inputs are too big, send an error
-            if photo_or_youtube_url.len() > 200 || message.len() > 200 {
-                return Err(Error::DataTooLarge);
-            }
-            // set up clones
-            let message_clone = message.clone();
-            // set up the caller
+            self.check_not_paused(false)?;
             let caller = Self::env().caller();
-            // is this your order? 
-            // get the order details from order_details: Mapping<Hash, Order>
+            if !self.arbiters.contains(&caller) {
+                return Err(Error::NotArbiter);
+            }
             let mut details = self.order_details.get(order_id).unwrap_or_default();
-            if details.seller == caller {
-                
-                let now = self.env().block_timestamp();
-                // make the message_id hash
-                let encodable = (caller, now, order_id, message); // Implements `scale::Encode`
-                let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
-                let new_message_id: Hash = Hash::from(new_id_u8);
-                
-                // update message_details: Mapping<Hash, MessageDetails>
-                let message_details = MessageDetails {
-                    message_id: new_message_id,
-                    from_acct: caller,
-                    to_acct: details.buyer,
-                    order_id: order_id,
-                    message: message_clone,
-                    media_url: photo_or_youtube_url,
-                    timestamp: now
-                };
-
-                // update order discussion
-                // if there is room in the discussion, add the message, otherwise error
-                if details.discussion.len() < 10 {
-                    details.discussion.push(message_details);
-                    // update order_details: Mapping<Hash, Order>
-                    self.order_details.insert(order_id, &details);
-                }
-                else {
-                    return Err(Error::StorageFull);
-                }
-                
+            if details.arbitration != Arbitration::Escalated {
+                return Err(Error::NotInArbitration);
             }
-            else {
-                return Err(Error::NotYourOrder)
+            // the buyer can never get back more than they paid
+            let refundable: Balance = details.total_order_price.saturating_sub(details.refunded_amount);
+            if refund == 0 || refund > refundable {
+                return Err(Error::InvalidRefundAmount);
+            }
+            let seller = details.seller;
+            let buyer = details.buyer;
+
+            // take the refund from escrow first, the rest of the escrow goes to the seller
+            let from_escrow: Balance = self.refund_from_escrow(&details, refund);
+            // then from the seller's bond
+            let from_bond: Balance = self.slash_bond(seller, order_id, refund.saturating_sub(from_escrow));
+            // then from whatever the seller has not yet withdrawn
+            let seller_balance = self.claimable_balance.get(seller).unwrap_or_default();
+            let from_seller_balance: Balance = refund.saturating_sub(from_escrow).saturating_sub(from_bond).min(seller_balance);
+            if from_seller_balance > 0 {
+                self.claimable_balance.insert(seller, &seller_balance.saturating_sub(from_seller_balance));
             }
+            let paid = from_escrow.saturating_add(from_bond).saturating_add(from_seller_balance);
+            if paid > 0 {
+                self.credit_account(buyer, order_id, paid, CreditReason::Refund);
+            }
+
+            // update order_details: Mapping<Hash, Order>
+            details.refunded_amount = details.refunded_amount.saturating_add(paid);
+            details.resolution = Resolution::Refunded;
+            details.arbitration = Arbitration::RuledRefund;
+            details.arbiter = caller;
+            self.add_timeline_entry(&mut details, caller, b"arbiter ruled refund");
+            self.order_details.insert(order_id, &details);
+            self.open_disputes.retain(|value| *value != order_id);
+
+            // update Seller profile
+            let mut sellerprofile = self.account_profile_seller.get(seller).unwrap_or_default();
+            sellerprofile.arbitrations_lost = sellerprofile.arbitrations_lost.saturating_add(1);
+            self.account_profile_seller.insert(seller, &sellerprofile);
+
+            // EMIT EVENT ArbitrationRuling
+            Self::env().emit_event(ArbitrationRuling {
+                order_id,
+                arbiter: caller,
+                seller,
+                buyer,
+                ruling: Arbitration::RuledRefund,
+                refund: paid,
+                from_escrow,
+                from_bond,
+                from_seller_balance,
+            });
+
             Ok(())
         }
 
 
-        // 19 🟢 Rate A Buyer
+        // 67 🟢 Arbiter Uphold Denial
+        // an arbiter agrees with the seller, any escrow held for the order is released to the seller
         #[ink(message)]
-        pub fn rate_a_buyer (&mut self, 
-            buyer: AccountId,
-            rating: u64,
-            review: Vec<u8>,
+        pub fn arbiter_uphold_denial (&mut self,
             order_id: Hash
         ) -> Result<(), Error> {
-            // if the rating is between 1 and 5
-            if rating > 0 && rating < 6 {
-                let caller = Self::env().caller();
-                let review_clone = review.clone();
-                let now = self.env().block_timestamp();
-                
-                // get the details for this order
-                let details = self.order_details.get(order_id).unwrap_or_default();
-                // account_seller_buyers_reviewed: Mapping<AccountId, AccountVector>
-                let mut reviewed = self.account_seller_buyers_reviewed.get(caller).unwrap_or_default();
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            if !self.arbiters.contains(&caller) {
+                return Err(Error::NotArbiter);
+            }
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.arbitration != Arbitration::Escalated {
+                return Err(Error::NotInArbitration);
+            }
 
-                // did you actually sell this order to this buyer?
-                if details.seller == caller {
-                    // have you already reviewed this buyer recently?
-                    if reviewed.accountvector.contains(&buyer) {
-                        return Err(Error::NotEligibleToReview)
-                    }
-                    else {
-                        // make the review_id hash
-                        let encodable = (caller, buyer, review); // Implements `scale::Encode`
-                        let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                        ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
-                        let new_review_id: Hash = Hash::from(new_id_u8);
+            // update order_details: Mapping<Hash, Order>
+            details.arbitration = Arbitration::Upheld;
+            details.arbiter = caller;
+            self.add_timeline_entry(&mut details, caller, b"arbiter upheld denial");
+            self.order_details.insert(order_id, &details);
+            self.open_disputes.retain(|value| *value != order_id);
+
+            // pay out anything still in escrow
+            self.release_order_escrow(&details, caller);
+
+            // update Buyer profile
+            let buyer = details.buyer;
+            let mut buyerprofile = self.account_profile_buyer.get(buyer).unwrap_or_default();
+            buyerprofile.arbitrations_lost = buyerprofile.arbitrations_lost.saturating_add(1);
+            self.account_profile_buyer.insert(buyer, &buyerprofile);
+
+            // EMIT EVENT ArbitrationRuling
+            Self::env().emit_event(ArbitrationRuling {
+                order_id,
+                arbiter: caller,
+                seller: details.seller,
+                buyer,
+                ruling: Arbitration::Upheld,
+                refund: 0,
+                from_escrow: 0,
+                from_bond: 0,
+                from_seller_balance: 0,
+            });
 
-                        // update mappings...
-                        // account_seller_buyers_reviewed: Mapping<AccountId, AccountVector>
-                        if reviewed.accountvector.len() > 399 {
-                            // remove the oldest
-                            reviewed.accountvector.remove(0);
-                        }
-                        reviewed.accountvector.push(buyer);
-                        self.account_seller_buyers_reviewed.insert(caller, &reviewed);
+            Ok(())
+        }
 
-                        // account_profile_buyer: Mapping<AccountId, BuyerProfile>
-                        let mut profile = self.account_profile_buyer.get(buyer).unwrap_or_default();
-                        let oldsum = profile.review_count.saturating_mul(profile.review_average);
-                        let newsum = oldsum.saturating_add(rating);
-                        // recalculate the review count
-                        profile.review_count = profile.review_count.saturating_add(1);
-                        // recalcualte the review average
-                        profile.review_average = newsum.div_euclid(profile.review_count);
-                        // return to storage
-                        self.account_profile_buyer.insert(buyer, &profile);
 
-                        // EMIT EVENT NewBuyerRating
-                        Self::env().emit_event(NewBuyerRating {
-                            review_id: new_review_id,
-                            buyer: buyer,
-                            reviewer: caller,
-                            rating: rating,
-                            review: review_clone,
-                            timestamp: now,
-                        });
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> RETURN MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
 
-                    }
-                }
-                else {
-                    // if you did not buy from this seller, error
-                    return Err(Error::NotEligibleToReview)
-                }
+        // 68 🟢 Authorize Return
+        // for a damaged, wrong or otherwise faulty item, the seller asks for the item back and sets aside the refund
+        // any escrow still held for the order counts toward the refund, the seller sends the rest
+        #[ink(message, payable)]
+        pub fn authorize_return (&mut self,
+            order_id: Hash,
+            return_address: Vec<u8>
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            if return_address.len() > 300 {
+                return Err(Error::DataTooLarge);
             }
-            else {
-                return Err(Error::RatingOutOfBounds)
+            let caller = Self::env().caller();
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.seller != caller {
+                return Err(Error::NotYourOrder);
+            }
+            // only open problems with a physical item to send back can be returned
+            let returnable = matches!(details.problem,
+                ProblemKind::Damaged | ProblemKind::WrongItem | ProblemKind::NotAsDescribed
+                | ProblemKind::Counterfeit | ProblemKind::MissingParts);
+            if details.order_status != OrderStatus::Problem || details.resolution != Resolution::None || !returnable {
+                return Err(Error::CannotReturn);
+            }
+            // the seller must send exactly what escrow does not already cover
+            let refundable: Balance = details.total_order_price.saturating_sub(details.refunded_amount);
+            let escrowed = self.order_escrow.get(order_id).unwrap_or_default();
+            let held: Balance = self.env().transferred_value();
+            if held != refundable.saturating_sub(escrowed) {
+                return Err(Error::InvalidRefundAmount);
             }
+
+            // a pending partial refund offer is replaced by the return
+            self.return_refund_offer(&mut details);
+
+            // update order_details: Mapping<Hash, Order>
+            self.transition(&mut details, OrderStatus::ReturnAuthorized, caller, b"return authorized")?;
+            details.return_address = return_address.clone();
+            details.return_refund_held = held;
+            details.time_return_authorized = self.env().block_timestamp();
+            self.order_details.insert(order_id, &details);
+
+            // EMIT EVENT ReturnAuthorized
+            Self::env().emit_event(ReturnAuthorized {
+                seller: caller,
+                buyer: details.buyer,
+                order_id,
+                order_status: OrderStatus::ReturnAuthorized,
+                return_address,
+                refund: refundable,
+            });
+
             Ok(())
         }
-        
-        
-        // 20 🟢 Add A Product
+
+
+        // 69 🟢 Submit Return Tracking
+        // the buyer sends the item back and gives the seller the tracking information
         #[ink(message)]
-        pub fn add_a_product (&mut self, 
-            digital: bool,
-            title: Vec<u8>,
-            price: Balance,
-            brand: Vec<u8>,
-            category: Vec<u8>,
-            description: Vec<u8>, 
-            inventory: u128, 
-            photo_or_youtube_link1: Vec<u8>, 
-            photo_or_youtube_link2: Vec<u8>,
-            photo_or_youtube_link3: Vec<u8>,
-            more_info_link: Vec<u8>,
-            delivery_info: Vec<u8>,
-            product_location: Vec<u8>,
-            digital_file_url: Vec<u8>,
-            zeno_percent: u128,
+        pub fn submit_return_tracking (&mut self,
+            order_id: Hash,
+            return_tracking: Vec<u8>
         ) -> Result<(), Error> {
-            // if the inputs are too big, send an error
-            if title.len() > 200 || brand.len() > 100 || category.len() > 100 || description.len() > 600
-            || photo_or_youtube_link1.len() > 200 || photo_or_youtube_link2.len() > 200
-            || photo_or_youtube_link3.len() > 200 || more_info_link.len() > 200
-            || delivery_info.len() > 200 || product_location.len() > 200 || digital_file_url.len() > 600 {
+            self.check_not_paused(false)?;
+            if return_tracking.len() > 200 {
                 return Err(Error::DataTooLarge);
             }
+            let caller = Self::env().caller();
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.buyer != caller {
+                return Err(Error::NotYourOrder);
+            }
+            if details.order_status != OrderStatus::ReturnAuthorized {
+                return Err(Error::CannotReturn);
+            }
 
-            // set up clones
-            let title_clone = title.clone();
-            // set up the caller
+            // update order_details: Mapping<Hash, Order>
+            self.transition(&mut details, OrderStatus::ReturnShipped, caller, b"return shipped")?;
+            details.return_tracking = return_tracking.clone();
+            details.time_return_shipped = self.env().block_timestamp();
+            self.order_details.insert(order_id, &details);
+
+            // EMIT EVENT ReturnShipped
+            Self::env().emit_event(ReturnShipped {
+                seller: details.seller,
+                buyer: caller,
+                order_id,
+                order_status: OrderStatus::ReturnShipped,
+                return_tracking,
+            });
+
+            Ok(())
+        }
+
+
+        // 70 🟢 Confirm Return Received
+        // the seller confirms the item came back, which restocks it and refunds the buyer
+        // if the seller never confirms, anyone can trigger the refund once the delivery window
+        // has passed since the buyer shipped the return
+        #[ink(message)]
+        pub fn confirm_return_received (&mut self,
+            order_id: Hash
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
             let caller = Self::env().caller();
+            let now = self.env().block_timestamp();
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.order_status != OrderStatus::ReturnShipped {
+                return Err(Error::CannotReturn);
+            }
+            if caller != details.seller
+            && now <= details.time_return_shipped.saturating_add(self.delivery_window) {
+                return Err(Error::ReturnNotExpired);
+            }
+            let seller = details.seller;
 
-            let mut seller_products = self.account_seller_products.get(caller).unwrap_or_default();
-            // if the seller's product list is full, send an error
-            if seller_products.hashvector.len() > 49 {
-                return Err(Error::StorageFull);
+            // refund the held amount plus whatever escrow is needed, the rest of the escrow goes to the seller
+            let refundable: Balance = details.total_order_price.saturating_sub(details.refunded_amount);
+            let from_escrow: Balance = self.refund_from_escrow(&details, refundable.saturating_sub(details.return_refund_held));
+            let refund = details.return_refund_held.saturating_add(from_escrow);
+
+            // the items are back, put them in inventory
+            if !details.restocked {
+                details.restocked = true;
+                self.restore_inventory(&details, 4);
             }
-            else {
 
-                let now = self.env().block_timestamp();
+            details.return_refund_held = 0;
+            details.time_return_received = now;
 
-                // make the product id hash
-                let encodable = (caller, title, now); // Implements `scale::Encode`
-                let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
-                let new_product_id: Hash = Hash::from(new_id_u8);
+            // EMIT EVENT ReturnReceived
+            Self::env().emit_event(ReturnReceived {
+                seller,
+                buyer: details.buyer,
+                order_id,
+                refund,
+                confirmed_by: caller,
+            });
 
-                // get the seller profile 
-                let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
+            // credit the buyer and close the order as returned and refunded
+            self.finish_refund(details, refund, Resolution::ReturnedAndRefunded, caller)?;
 
-                // set up the product details
-                let product = Product {
-                    product_id: new_product_id,
-                    digital: digital,
-                    title: title_clone.clone(),
-                    price: price,
-                    brand: brand.clone(),
-                    category: category.clone(),
-                    seller_account: caller,
-                    seller_name: sellerprofile.seller_name.clone(),
-                    description: description.clone(), 
-                    review_average: u64::default(),
-                    review_count: u64::default(),
-                    inventory: inventory, 
-                    photo_or_youtube_link1: photo_or_youtube_link1.clone(), 
-                    photo_or_youtube_link2: photo_or_youtube_link2.clone(),
-                    photo_or_youtube_link3: photo_or_youtube_link3.clone(),
-                    more_info_link: more_info_link.clone(),
-                    delivery_info: delivery_info.clone(),
-                    product_location: product_location.clone(),
-                    digital_file_url: digital_file_url,
-                    zeno_percent: zeno_percent,
-                    zeno_buyers: <Vec<AccountId>>::default(),
-                };
+            Ok(())
+        }
 
-                // UPDATE MAPPINGS...
-                // total_count_products: u128
-                self.total_count_products = self.total_count_products.saturating_add(1);
 
-                // account_seller_products: Mapping<AccountId, HashVector>
-                seller_products.hashvector.push(new_product_id);
-                self.account_seller_products.insert(caller, &seller_products);
+        // 71 🟢 Cancel Return Authorization
+        // if the buyer has not shipped the item back within 7 days, the seller can withdraw
+        // the authorization, get back what they paid in, and the problem is open again
+        #[ink(message)]
+        pub fn cancel_return_authorization (&mut self,
+            order_id: Hash
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            let now = self.env().block_timestamp();
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.seller != caller {
+                return Err(Error::NotYourOrder);
+            }
+            if details.order_status != OrderStatus::ReturnAuthorized {
+                return Err(Error::CannotReturn);
+            }
+            if now <= details.time_return_authorized.saturating_add(604800000) {
+                return Err(Error::ReturnNotExpired);
+            }
+            if details.return_refund_held > 0 {
+                self.credit_account(caller, order_id, details.return_refund_held, CreditReason::Refund);
+            }
 
-                // product_details: Mapping<Hash, Product>
-                self.product_details.insert(new_product_id, &product);
+            // update order_details: Mapping<Hash, Order>
+            self.transition(&mut details, OrderStatus::Problem, caller, b"return authorization cancelled")?;
+            details.return_refund_held = 0;
+            self.order_details.insert(order_id, &details);
+
+            // EMIT EVENT ReturnAuthorizationCancelled
+            Self::env().emit_event(ReturnAuthorizationCancelled {
+                seller: caller,
+                buyer: details.buyer,
+                order_id,
+                order_status: OrderStatus::Problem,
+            });
 
-                // if this is the first product, set the member_since timestamp
-                if sellerprofile.member_since == u64::default() {
-                    sellerprofile.member_since = now;
-                    // add this seller to all_sellers StorageVec
-                    self.all_sellers.push(&caller);
-                }
-                self.account_profile_seller.insert(caller, &sellerprofile);
+            Ok(())
+        }
 
-                // EMIT EVENT NewProduct
-                Self::env().emit_event(NewProduct {
-                    product_id: new_product_id,
-                    digital: digital,
-                    title: title_clone,
-                    price: price,
-                    brand: brand,
-                    category: category,
-                    seller_account: caller,
-                    seller_name: sellerprofile.seller_name,
-                    description: description, 
-                    inventory: inventory, 
-                    photo_or_youtube_link1: photo_or_youtube_link1, 
-                    photo_or_youtube_link2: photo_or_youtube_link2,
-                    photo_or_youtube_link3: photo_or_youtube_link3,
-                    more_info_link: more_info_link,
-                    delivery_info: delivery_info,
-                    product_location: product_location,
-                    zeno_percent: zeno_percent,
-                });
+
+        // 72 🟢 View Order Timeline
+        // every status change and dispute step on an order, with when it happened and who did it
+        #[ink(message)]
+        pub fn view_order_timeline (&self,
+            order_id: Hash
+        ) -> Vec<StatusChange> {
+            self.order_details.get(order_id).unwrap_or_default().timeline
+        }
+
+
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> SERVICE MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
 
+        // 73 🟢 Schedule Service
+        // the seller sets (or moves) the date a service will be rendered
+        // the buyer's problem window starts from this date unless the service is marked completed
+        #[ink(message)]
+        pub fn schedule_service (&mut self,
+            order_id: Hash,
+            service_date: u64
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.seller != caller {
+                return Err(Error::NotYourOrder);
             }
-            
+            if !details.is_service || details.order_status != OrderStatus::Delivered
+            || details.time_service_completed > 0 {
+                return Err(Error::NotAService);
+            }
+            // a date in the past would start (and possibly close) the problem window right away
+            if service_date < self.env().block_timestamp() {
+                return Err(Error::InvalidServiceDate);
+            }
+            details.service_date = service_date;
+            self.add_timeline_entry(&mut details, caller, b"service scheduled");
+            self.order_details.insert(order_id, &details);
+
+            // EMIT EVENT ServiceScheduled
+            Self::env().emit_event(ServiceScheduled {
+                seller: caller,
+                buyer: details.buyer,
+                order_id,
+                service_date,
+            });
+
             Ok(())
         }
 
-        
-        // 21 🟢 Update Product Details 
+
+        // 74 🟢 Mark Service Completed
+        // the seller marks the service rendered, which starts the buyer's problem window
         #[ink(message)]
-        pub fn update_product_details (&mut self,
-            product_id: Hash, 
-            price: Balance,
-            brand: Vec<u8>,
-            category: Vec<u8>,
-            description: Vec<u8>, 
-            inventory: u128, 
-            photo_or_youtube_link1: Vec<u8>, 
-            photo_or_youtube_link2: Vec<u8>,
-            photo_or_youtube_link3: Vec<u8>,
-            more_info_link: Vec<u8>,
-            delivery_info: Vec<u8>,
-            product_location: Vec<u8>,
-            digital_file_url: Vec<u8>,
+        pub fn mark_service_completed (&mut self,
+            order_id: Hash
         ) -> Result<(), Error> {
-            if brand.len() > 100 || category.len() > 100 || description.len() > 600
-            || photo_or_youtube_link1.len() > 200 || photo_or_youtube_link2.len() > 200
-            || photo_or_youtube_link3.len() > 200 || more_info_link.len() > 200
-            || delivery_info.len() > 200 || product_location.len() > 200 || digital_file_url.len() > 600 {
-                return Err(Error::DataTooLarge);
-            }
-            // set up the caller
+            self.check_not_paused(false)?;
             let caller = Self::env().caller();
+            let now = self.env().block_timestamp();
+            let mut details = self.order_details.get(order_id).unwrap_or_default();
+            if details.seller != caller {
+                return Err(Error::NotYourOrder);
+            }
+            if !details.is_service || details.order_status != OrderStatus::Delivered
+            || details.time_service_completed > 0 {
+                return Err(Error::NotAService);
+            }
+            details.time_service_completed = now;
+            self.add_timeline_entry(&mut details, caller, b"service completed");
+            self.order_details.insert(order_id, &details);
+
+            // EMIT EVENT ServiceCompleted
+            Self::env().emit_event(ServiceCompleted {
+                seller: caller,
+                buyer: details.buyer,
+                order_id,
+                time_service_completed: now,
+            });
 
-            // is this your product? Check the product id
-            // account_seller_products: Mapping<AccountId, HashVector>
-            let seller_products = self.account_seller_products.get(caller).unwrap_or_default();
-            if seller_products.hashvector.contains(&product_id) {
-                
-                // get the current product details
-                let details = self.product_details.get(product_id).unwrap_or_default();
+            Ok(())
+        }
 
-                // set up the product details update
-                let update = Product {
-                    product_id: product_id,
-                    digital: details.digital,
-                    title: details.title.clone(),
-                    price: price,
-                    brand: brand.clone(),
-                    category: category.clone(),
-                    seller_account: caller,
-                    seller_name: details.seller_name.clone(),
-                    description: description.clone(),
-                    review_average: details.review_average,
-                    review_count: details.review_count, 
-                    inventory: inventory, 
-                    photo_or_youtube_link1: photo_or_youtube_link1.clone(), 
-                    photo_or_youtube_link2: photo_or_youtube_link2.clone(),
-                    photo_or_youtube_link3: photo_or_youtube_link3.clone(),
-                    more_info_link: more_info_link.clone(),
-                    delivery_info: delivery_info.clone(),
-                    product_location: product_location.clone(),
-                    digital_file_url: digital_file_url,
-                    zeno_percent: details.zeno_percent,
-                    zeno_buyers: details.zeno_buyers,
-                };
 
-                // update product_details: Mapping<Hash, Product>
-                self.product_details.insert(product_id, &update);
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> DISPUTE POLICY MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
-                // EMIT EVENT UpdatedProduct
-                Self::env().emit_event(UpdatedProduct {
-                    product_id: product_id,
-                    digital: details.digital,
-                    title: details.title,
-                    price: price,
-                    brand: brand,
-                    category: category,
-                    seller_account: caller,
-                    seller_name: details.seller_name,
-                    description: description, 
-                    inventory: inventory, 
-                    photo_or_youtube_link1: photo_or_youtube_link1, 
-                    photo_or_youtube_link2: photo_or_youtube_link2,
-                    photo_or_youtube_link3: photo_or_youtube_link3,
-                    more_info_link: more_info_link,
-                    delivery_info: delivery_info,
-                    product_location: product_location,
-                });
 
+        // 75 🟢 Set Dispute Window
+        // the seller's store-wide policy for how long (ms) buyers have to report a problem
+        // 0 goes back to the 24 hour default, otherwise 1 hour to 30 days
+        // orders already placed keep the window they were bought with
+        #[ink(message)]
+        pub fn set_dispute_window (&mut self,
+            dispute_window: u64
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            if dispute_window != 0 && !(3600000..=2592000000).contains(&dispute_window) {
+                return Err(Error::InvalidDisputeWindow);
             }
-            
-            else {
-                return Err(Error::NotYourProduct)
-            }
+            let caller = Self::env().caller();
+            let mut profile = self.account_profile_seller.get(caller).unwrap_or_default();
+            profile.seller_account = caller;
+            profile.dispute_window = dispute_window;
+            self.account_profile_seller.insert(caller, &profile);
+
+            // EMIT EVENT DisputeWindowUpdated
+            Self::env().emit_event(DisputeWindowUpdated {
+                seller_account: caller,
+                item_id: Hash::default(),
+                dispute_window,
+            });
 
             Ok(())
         }
-        
 
-        // 22 🟢 Add A Service
+
+        // 76 🟢 Set Listing Dispute Window
+        // overrides the seller's policy for one product or service, 0 goes back to the seller's policy
         #[ink(message)]
-        pub fn add_a_service (&mut self, 
-            online: bool,
-            title: Vec<u8>,
-            price: Balance,
-            category: Vec<u8>,
-            description: Vec<u8>,  
-            inventory: u128,
-            photo_or_youtube_link1: Vec<u8>, 
-            photo_or_youtube_link2: Vec<u8>,
-            photo_or_youtube_link3: Vec<u8>,
-            booking_link: Vec<u8>,
-            service_location: Vec<u8>,
-            zeno_percent: u128,
+        pub fn set_listing_dispute_window (&mut self,
+            item_id: Hash,
+            dispute_window: u64
         ) -> Result<(), Error> {
-            if title.len() > 200 || category.len() > 100 || description.len() > 600
-            || photo_or_youtube_link1.len() > 200 || photo_or_youtube_link2.len() > 200
-            || photo_or_youtube_link3.len() > 200 || booking_link.len() > 200
-            || service_location.len() > 200 {
-                return Err(Error::DataTooLarge);
+            self.check_not_paused(false)?;
+            if dispute_window != 0 && !(3600000..=2592000000).contains(&dispute_window) {
+                return Err(Error::InvalidDisputeWindow);
             }
-            // set up clones
-            let title_clone = title.clone();
-            // set up the caller
             let caller = Self::env().caller();
-
-            let mut seller_services = self.account_seller_services.get(caller).unwrap_or_default();
-            // if the seller's service list is full, send an error
-            if seller_services.hashvector.len() > 49 {
-                return Err(Error::StorageFull);
+            // is this your product or service?
+            let seller_products = self.account_seller_products.get(caller).unwrap_or_default();
+            let seller_services = self.account_seller_services.get(caller).unwrap_or_default();
+            if seller_products.hashvector.contains(&item_id) {
+                let mut details = self.product_details.get(item_id).unwrap_or_default();
+                details.dispute_window = dispute_window;
+                self.product_details.insert(item_id, &details);
+            }
+            else if seller_services.hashvector.contains(&item_id) {
+                let mut details = self.service_details.get(item_id).unwrap_or_default();
+                details.dispute_window = dispute_window;
+                self.service_details.insert(item_id, &details);
             }
             else {
-                let now = self.env().block_timestamp();
+                return Err(Error::NotYourProduct);
+            }
 
-                // make the product id hash
-                let encodable = (caller, title, now); // Implements `scale::Encode`
-                let mut new_id_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut new_id_u8);
-                let new_service_id: Hash = Hash::from(new_id_u8);
+            // EMIT EVENT DisputeWindowUpdated
+            Self::env().emit_event(DisputeWindowUpdated {
+                seller_account: caller,
+                item_id,
+                dispute_window,
+            });
 
-                // get the seller profile 
-                let mut sellerprofile = self.account_profile_seller.get(caller).unwrap_or_default();
+            Ok(())
+        }
 
-                // set up the service details
-                let service = Service {
-                    service_id: new_service_id,
-                    online: online,
-                    title: title_clone.clone(),
-                    price: price,
-                    category: category.clone(),
-                    seller_account: caller,
-                    seller_name: sellerprofile.seller_name.clone(),
-                    description: description.clone(),
-                    review_average: u64::default(),
-                    review_count: u64::default(),
-                    inventory: inventory,
-                    photo_or_youtube_link1: photo_or_youtube_link1.clone(), 
-                    photo_or_youtube_link2: photo_or_youtube_link2.clone(),
-                    photo_or_youtube_link3: photo_or_youtube_link3.clone(),
-                    booking_link: booking_link.clone(),
-                    service_location: service_location.clone(),
-                    zeno_percent: zeno_percent,
-                    zeno_buyers: <Vec<AccountId>>::default(),
-                };
 
-                // UPDATE MAPPINGS ...
-                // total_count_services u128
-                self.total_count_services = self.total_count_services.saturating_add(1);
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> SELLER BOND MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
-                // account_seller_services: Mapping<AccountId, HashVector>
-                seller_services.hashvector.push(new_service_id);
-                self.account_seller_services.insert(caller, &seller_services);
 
-                // service_details: Mapping<Hash, Service>
-                self.service_details.insert(new_service_id, &service);
+        // 77 🟢 Deposit Bond
+        // the seller stakes a security deposit, which shows the bond-backed badge on their profile
+        // and can be slashed to pay buyers when an arbiter rules against the seller
+        // or the seller abandons an order. Depositing again cancels a pending withdrawal.
+        #[ink(message, payable)]
+        pub fn deposit_bond (&mut self) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            let amount: Balance = self.env().transferred_value();
+            if amount == 0 {
+                return Err(Error::InsufficientPayment);
+            }
+            let mut profile = self.account_profile_seller.get(caller).unwrap_or_default();
+            profile.seller_account = caller;
+            profile.bond = profile.bond.saturating_add(amount);
+            profile.bond_backed = true;
+            profile.bond_withdrawal_requested = 0;
+            self.account_profile_seller.insert(caller, &profile);
 
-                // if this is the first product or service, set the member_since timestamp
-                // and add the seller to the all_sellers StorageVec
-                if sellerprofile.member_since == u64::default() {
-                    sellerprofile.member_since = now;
-                    self.all_sellers.push(&caller);
-                }
-                self.account_profile_seller.insert(caller, &sellerprofile);
+            // EMIT EVENT BondDeposited
+            Self::env().emit_event(BondDeposited {
+                seller: caller,
+                amount,
+                bond: profile.bond,
+            });
 
-                // EMIT EVENT NewService
-                Self::env().emit_event(NewService {
-                    service_id: new_service_id,
-                    online: online,
-                    title: title_clone,
-                    price: price,
-                    category: category,
-                    seller_account: caller,
-                    seller_name: sellerprofile.seller_name,
-                    description: description,
-                    inventory: inventory,
-                    photo_or_youtube_link1: photo_or_youtube_link1, 
-                    photo_or_youtube_link2: photo_or_youtube_link2,
-                    photo_or_youtube_link3: photo_or_youtube_link3,
-                    booking_link: booking_link,
-                    service_location: service_location,
-                    zeno_percent: zeno_percent,
-                });
+            Ok(())
+        }
 
+
+        // 78 🟢 Request Bond Withdrawal
+        // starts the 14 day cooldown, the badge comes off right away
+        // the bond can still be slashed during the cooldown
+        #[ink(message)]
+        pub fn request_bond_withdrawal (&mut self) -> Result<(), Error> {
+            self.check_not_paused(false)?;
+            let caller = Self::env().caller();
+            let now = self.env().block_timestamp();
+            let mut profile = self.account_profile_seller.get(caller).unwrap_or_default();
+            if profile.bond == 0 {
+                return Err(Error::NothingToWithdraw);
             }
-            
+            profile.bond_backed = false;
+            profile.bond_withdrawal_requested = now;
+            self.account_profile_seller.insert(caller, &profile);
+
+            // EMIT EVENT BondWithdrawalRequested
+            Self::env().emit_event(BondWithdrawalRequested {
+                seller: caller,
+                bond: profile.bond,
+                available_after: now.saturating_add(1209600000),
+            });
+
             Ok(())
         }
-        
 
-        // 23 🟢 Update Service Details
+
+        // 79 🟢 Withdraw Bond
+        // after the cooldown, and once the seller has no unfinished orders or open disputes,
+        // the bond goes to the seller's claimable balance
         #[ink(message)]
-        pub fn update_service_details (&mut self,
-            service_id: Hash, 
-            price: Balance,
-            category: Vec<u8>,
-            description: Vec<u8>, 
-            inventory: u128, 
-            photo_or_youtube_link1: Vec<u8>, 
-            photo_or_youtube_link2: Vec<u8>,
-            photo_or_youtube_link3: Vec<u8>,
-            booking_link: Vec<u8>,
-            service_location: Vec<u8>,
-        ) -> Result<(), Error> {
-            if category.len() > 100 || description.len() > 600
-            || photo_or_youtube_link1.len() > 200 || photo_or_youtube_link2.len() > 200
-            || photo_or_youtube_link3.len() > 200 || booking_link.len() > 200
-            || service_location.len() > 200 {
-                return Err(Error::DataTooLarge);
-            }
-            // set up the caller
+        pub fn withdraw_bond (&mut self) -> Result<(), Error> {
+            self.check_not_paused(false)?;
             let caller = Self::env().caller();
+            let now = self.env().block_timestamp();
+            let mut profile = self.account_profile_seller.get(caller).unwrap_or_default();
+            if profile.bond == 0 {
+                return Err(Error::NothingToWithdraw);
+            }
+            if profile.bond_withdrawal_requested == 0
+            || now < profile.bond_withdrawal_requested.saturating_add(1209600000) {
+                return Err(Error::BondCooldown);
+            }
+            // no awaiting, shipped, delivered or problem orders, nothing waiting on an arbiter,
+            // and no denied problems the buyer can still escalate
+            let open = !self.account_seller_orders_0awaiting.get(caller).unwrap_or_default().hashvector.is_empty()
+            || !self.account_seller_orders_1shipped.get(caller).unwrap_or_default().hashvector.is_empty()
+            || !self.account_seller_orders_2delivered.get(caller).unwrap_or_default().hashvector.is_empty()
+            || !self.account_seller_orders_4problem.get(caller).unwrap_or_default().hashvector.is_empty()
+            || self.open_disputes.iter().any(|id| self.order_details.get(id).unwrap_or_default().seller == caller)
+            || self.account_seller_orders_3resolved.get(caller).unwrap_or_default().hashvector.iter().any(|id| {
+                let details = self.order_details.get(id).unwrap_or_default();
+                details.resolution == Resolution::Denied && details.arbitration == Arbitration::None
+                && now <= details.time_denied.saturating_add(ESCALATION_WINDOW)
+            });
+            if open {
+                return Err(Error::OpenOrders);
+            }
 
-            // is this your service? Check the service id
-            // account_seller_services: Mapping<AccountId, HashVector>
-            let seller_services = self.account_seller_services.get(caller).unwrap_or_default();
-            if seller_services.hashvector.contains(&service_id) {
-                
-                // get the current service details
-                let details = self.service_details.get(service_id).unwrap_or_default();
+            let amount = profile.bond;
+            profile.bond = 0;
+            profile.bond_withdrawal_requested = 0;
+            self.account_profile_seller.insert(caller, &profile);
+            self.credit_account(caller, Hash::default(), amount, CreditReason::BondReturned);
 
-                // set up the service details update
-                let update = Service {
-                    service_id: service_id,
-                    online: details.online,
-                    title: details.title.clone(),
-                    price: price,
-                    category: category.clone(),
-                    seller_account: caller,
-                    seller_name: details.seller_name.clone(),
-                    description: description.clone(),
-                    review_average: details.review_average,
-                    review_count: details.review_count,
-                    inventory: inventory,
-                    photo_or_youtube_link1: photo_or_youtube_link1.clone(), 
-                    photo_or_youtube_link2: photo_or_youtube_link2.clone(),
-                    photo_or_youtube_link3: photo_or_youtube_link3.clone(),
-                    booking_link: booking_link.clone(),
-                    service_location: service_location.clone(),
-                    zeno_percent: details.zeno_percent,
-                    zeno_buyers: details.zeno_buyers,
-                };
+            // EMIT EVENT BondWithdrawn
+            Self::env().emit_event(BondWithdrawn {
+                seller: caller,
+                amount,
+            });
 
-                // update service_details: Mapping<Hash, Service>
-                self.service_details.insert(service_id, &update);
+            Ok(())
+        }
 
-                // EMIT EVENT UpdatedService
-                Self::env().emit_event(UpdatedService {
-                    service_id: service_id,
-                    online: details.online,
-                    title: details.title,
-                    price: price,
-                    category: category,
-                    seller_account: caller,
-                    seller_name: details.seller_name,
-                    description: description,
-                    inventory: inventory,
-                    photo_or_youtube_link1: photo_or_youtube_link1, 
-                    photo_or_youtube_link2: photo_or_youtube_link2,
-                    photo_or_youtube_link3: photo_or_youtube_link3,
-                    booking_link: booking_link,
-                    service_location: service_location,
-                });
 
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> BUYER PROTECTION MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+
+        // 80 🟢 Set Protection Settings
+        // admin only. protection_bps is the slice of each sale (100 = 1%) set aside for the pool,
+        // 0 turns the pool off. The caps limit what one order and one buyer can ever be paid.
+        #[ink(message)]
+        pub fn set_protection_settings (&mut self,
+            protection_bps: u128,
+            claim_cap_per_order: Balance,
+            claim_cap_per_buyer: Balance
+        ) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if caller != self.admin {
+                return Err(Error::NotAdmin);
             }
-            else {
-                return Err(Error::NotYourProduct)
+            if protection_bps.saturating_add(self.platform_fee_bps) > 10000 {
+                return Err(Error::FeeTooHigh);
             }
+            self.protection_bps = protection_bps;
+            self.claim_cap_per_order = claim_cap_per_order;
+            self.claim_cap_per_buyer = claim_cap_per_buyer;
+
+            // EMIT EVENT ProtectionSettingsUpdated
+            Self::env().emit_event(ProtectionSettingsUpdated {
+                admin: caller,
+                protection_bps,
+                claim_cap_per_order,
+                claim_cap_per_buyer,
+            });
 
             Ok(())
         }
 
 
-        // 24 🟢 Delete A Product
+        // 81 🟢 File Protection Claim
+        // a buyer whose problem the seller denied (and that no arbiter refunded)
+        // can ask the pool to cover up to what they paid, within the caps
         #[ink(message)]
-        pub fn delete_a_product (&mut self, product_id_to_delete: Hash) -> Result<(), Error> {
-            // set up the caller
+        pub fn file_protection_claim (&mut self,
+            order_id: Hash,
+            amount: Balance
+        ) -> Result<(), Error> {
+            self.check_not_paused(false)?;
             let caller = Self::env().caller();
-            // is this your product?
-            // get the seller's products
-            let mut products = self.account_seller_products.get(caller).unwrap_or_default();
-            if products.hashvector.contains(&product_id_to_delete) {
-                // remove this product from account_seller_products
-                products.hashvector.retain(|value| *value != product_id_to_delete);
-                self.account_seller_products.insert(caller, &products);
-                // remove this product from product_details
-                self.product_details.remove(product_id_to_delete);
-                // reduce total_count_products by one
-                self.total_count_products = self.total_count_products.saturating_sub(1);
+            let now = self.env().block_timestamp();
+            let details = self.order_details.get(order_id).unwrap_or_default();
+            if details.buyer != caller {
+                return Err(Error::NotYourOrder);
+            }
+            // denied, not waiting on or refunded by an arbiter, not refunded at all, and no earlier claim
+            if details.resolution != Resolution::Denied || details.arbitration == Arbitration::Escalated || details.arbitration == Arbitration::RuledRefund
+            || details.refunded_amount > 0 || self.protection_claims.contains(order_id) {
+                return Err(Error::CannotClaim);
+            }
+            let refundable: Balance = details.total_order_price.saturating_sub(details.refunded_amount);
+            let paid_so_far = self.account_protection_paid.get(caller).unwrap_or_default();
+            if amount == 0 || amount > refundable || amount > self.claim_cap_per_order
+            || paid_so_far.saturating_add(amount) > self.claim_cap_per_buyer {
+                return Err(Error::CannotClaim);
+            }
+
+            let claim = ProtectionClaim {
+                order_id,
+                buyer: caller,
+                amount_requested: amount,
+                amount_paid: 0,
+                status: ClaimStatus::Pending,
+                time_filed: now,
+                time_decided: 0,
+            };
+            self.protection_claims.insert(order_id, &claim);
+            // keep the 200 most recent claims in the public history,
+            // older claims stay in protection_claims so they can still be decided
+            if self.protection_claim_history.len() > 199 {
+                self.protection_claim_history.remove(0);
+            }
+            self.protection_claim_history.push(order_id);
+
+            // EMIT EVENT ProtectionClaimFiled
+            Self::env().emit_event(ProtectionClaimFiled {
+                buyer: caller,
+                order_id,
+                amount_requested: amount,
+            });
+
+            Ok(())
+        }
+
+
+        // 82 🟢 Approve Protection Claim
+        // admin only, pays the claim out of the pool (or as much as the pool holds)
+        #[ink(message)]
+        pub fn approve_protection_claim (&mut self,
+            order_id: Hash
+        ) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if caller != self.admin {
+                return Err(Error::NotAdmin);
             }
-            else {
-                return Err(Error::NotYourProduct);
+            let mut claim = self.protection_claims.get(order_id).unwrap_or_default();
+            if claim.amount_requested == 0 || claim.status != ClaimStatus::Pending {
+                return Err(Error::NoPendingClaim);
             }
-            
+            // the order must still be an unrefunded denial that no arbiter has taken up
+            let details = self.order_details.get(order_id).unwrap_or_default();
+            if details.arbitration == Arbitration::Escalated || details.arbitration == Arbitration::RuledRefund || details.refunded_amount > 0 {
+                return Err(Error::CannotClaim);
+            }
+            // the per buyer cap is checked again in case other claims were paid in the meantime
+            let paid_so_far = self.account_protection_paid.get(claim.buyer).unwrap_or_default();
+            let amount: Balance = claim.amount_requested
+                .min(self.protection_pool)
+                .min(self.claim_cap_per_buyer.saturating_sub(paid_so_far));
+            // nothing can be paid yet, leave the claim pending
+            if amount == 0 {
+                return Err(Error::ClaimNotPayable);
+            }
+
+            self.protection_pool = self.protection_pool.saturating_sub(amount);
+            self.protection_paid_total = self.protection_paid_total.saturating_add(amount);
+            self.account_protection_paid.insert(claim.buyer, &paid_so_far.saturating_add(amount));
+            self.credit_account(claim.buyer, order_id, amount, CreditReason::ProtectionClaim);
+
+            claim.amount_paid = amount;
+            claim.status = ClaimStatus::Approved;
+            claim.time_decided = self.env().block_timestamp();
+            self.protection_claims.insert(order_id, &claim);
+
+            // EMIT EVENT ProtectionClaimDecided
+            Self::env().emit_event(ProtectionClaimDecided {
+                buyer: claim.buyer,
+                order_id,
+                approved: true,
+                amount_paid: amount,
+                decided_by: caller,
+            });
+
             Ok(())
         }
 
-        // 25 🟢 Delete A Service
+
+        // 83 🟢 Reject Protection Claim
+        // admin only
         #[ink(message)]
-        pub fn delete_a_service (&mut self, service_id_to_delete: Hash) -> Result<(), Error> {
-            // set up the caller
+        pub fn reject_protection_claim (&mut self,
+            order_id: Hash
+        ) -> Result<(), Error> {
             let caller = Self::env().caller();
-            // is this your service?
-            // get the seller's services
-            let mut services = self.account_seller_services.get(caller).unwrap_or_default();
-            if services.hashvector.contains(&service_id_to_delete) {
-                // remove this service from account_seller_services
-                services.hashvector.retain(|value| *value != service_id_to_delete);
-                self.account_seller_services.insert(caller, &services);
-                // remove this service from service_details
-                self.service_details.remove(service_id_to_delete);
-                // reduce total_count_services by one
-                self.total_count_services = self.total_count_services.saturating_sub(1);
+            if caller != self.admin {
+                return Err(Error::NotAdmin);
             }
-            else {
-                return Err(Error::NotYourProduct);
+            let mut claim = self.protection_claims.get(order_id).unwrap_or_default();
+            if claim.amount_requested == 0 || claim.status != ClaimStatus::Pending {
+                return Err(Error::NoPendingClaim);
             }
-            
+            claim.status = ClaimStatus::Rejected;
+            claim.time_decided = self.env().block_timestamp();
+            self.protection_claims.insert(order_id, &claim);
+
+            // EMIT EVENT ProtectionClaimDecided
+            Self::env().emit_event(ProtectionClaimDecided {
+                buyer: claim.buyer,
+                order_id,
+                approved: false,
+                amount_paid: 0,
+                decided_by: caller,
+            });
+
             Ok(())
         }
 
 
-        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-        // >>>>>>>>>>>>>>>>>>>>>>>>>> PRIMARY GET MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
- 
-
-        // 26 🟢 Search Products By Keyword
+        // 84 🟢 View Protection Pool
+        // the pool balance, settings, totals and the most recent claims
         #[ink(message)]
-        pub fn search_products_by_keyword (&self,
-            keywords1: Vec<u8>,
-            keywords2: Vec<u8>,
-            keywords3: Vec<u8>
-        ) -> ProductSearchResults {
-            // set up the search targets
-            let targetvecu81 = keywords1.clone();
-            let target_string1 = String::from_utf8(targetvecu81).unwrap_or_default();
-            let targetvecu82 = keywords2.clone();
-            let target_string2 = String::from_utf8(targetvecu82).unwrap_or_default();
-            let targetvecu83 = keywords3.clone();
-            let target_string3 = String::from_utf8(targetvecu83).unwrap_or_default();
+        pub fn view_protection_pool (&self) -> ProtectionPool {
+            let mut claims = <Vec<ProtectionClaim>>::default();
+            for id in self.protection_claim_history.iter() {
+                claims.push(self.protection_claims.get(id).unwrap_or_default());
+            }
+            ProtectionPool {
+                balance: self.protection_pool,
+                protection_bps: self.protection_bps,
+                claim_cap_per_order: self.claim_cap_per_order,
+                claim_cap_per_buyer: self.claim_cap_per_buyer,
+                total_funded: self.protection_funded_total,
+                total_paid: self.protection_paid_total,
+                claims,
+            }
+        }
 
-            // set up return structures
-            let mut product_results = <Vec<PublicProduct>>::default();
 
-            // iterate over all_sellers: StorageVec<AccountId> to find matching results
-            if self.all_sellers.len() > 0 {
-                for i in 0..self.all_sellers.len() {
-                    let seller = self.all_sellers.get(i).unwrap();
-                    // get the seller's products
-                    let seller_products = self.account_seller_products.get(seller).unwrap_or_default();
-                    for item in seller_products.hashvector.iter() {
-                        // get the details
-                        let details = self.product_details.get(item).unwrap_or_default();
-                        // check to see if the keywords are there
-                        let title_string = String::from_utf8(details.title.clone()).unwrap_or_default();
-                        let seller_string = String::from_utf8(details.seller_name.clone()).unwrap_or_default();
-                        let brand_string = String::from_utf8(details.brand.clone()).unwrap_or_default();
-                        let category_string = String::from_utf8(details.category.clone()).unwrap_or_default();
-                        let description_string = String::from_utf8(details.description.clone()).unwrap_or_default();
-                        let delivery_string = String::from_utf8(details.delivery_info.clone()).unwrap_or_default();
-                        let location_string = String::from_utf8(details.product_location.clone()).unwrap_or_default();
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>> ADMIN MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
-                        // if the target_string is in the details
-                        if (title_string.contains(&target_string1) || brand_string.contains(&target_string1) ||
-                        category_string.contains(&target_string1) || description_string.contains(&target_string1) ||
-                        delivery_string.contains(&target_string1) || location_string.contains(&target_string1) ||
-                        seller_string.contains(&target_string1))
-                        && (title_string.contains(&target_string2) || brand_string.contains(&target_string2) ||
-                        category_string.contains(&target_string2) || description_string.contains(&target_string2) ||
-                        delivery_string.contains(&target_string2) || location_string.contains(&target_string2) ||
-                        seller_string.contains(&target_string2))
-                        && (title_string.contains(&target_string3) || brand_string.contains(&target_string3) ||
-                        category_string.contains(&target_string3) || description_string.contains(&target_string3) ||
-                        delivery_string.contains(&target_string3) || location_string.contains(&target_string3) ||
-                        seller_string.contains(&target_string3)) {
-                            
-                            // make the public product structure
-                            let public_product = PublicProduct {
-                                product_id: details.product_id,
-                                digital: details.digital,
-                                title: details.title,
-                                price: details.price,
-                                brand: details.brand,
-                                category: details.category,
-                                seller_account: details.seller_account,
-                                seller_name: details.seller_name,
-                                description: details.description,
-                                review_average: details.review_average,
-                                review_count: details.review_count,
-                                inventory: details.inventory, 
-                                photo_or_youtube_link1: details.photo_or_youtube_link1, 
-                                photo_or_youtube_link2: details.photo_or_youtube_link2,
-                                photo_or_youtube_link3: details.photo_or_youtube_link3,
-                                more_info_link: details.more_info_link,
-                                delivery_info: details.delivery_info,
-                                product_location: details.product_location,
-                                zeno_percent: details.zeno_percent,
-                                zeno_buyers: details.zeno_buyers
-                            };
 
-                            // add it to the results vector
-                            product_results.push(public_product);
-                        }
-                        // continue iterating on this seller's products
-                    }
-                    // continue iterating on all the sellers
-                }
+        // 85 🟢 Set Pause (admin only)
+        // paused_all stops every state changing message except the admin messages,
+        // paused_checkout stops only new orders so existing orders can still finish
+        #[ink(message)]
+        pub fn set_pause (&mut self,
+            paused_all: bool,
+            paused_checkout: bool
+        ) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if caller != self.admin {
+                return Err(Error::NotAdmin);
             }
+            self.paused_all = paused_all;
+            self.paused_checkout = paused_checkout;
+
+            // EMIT EVENT PauseUpdated
+            Self::env().emit_event(PauseUpdated {
+                admin: caller,
+                paused_all,
+                paused_checkout,
+            });
 
-            // package the results
-            let results = ProductSearchResults {
-                search: vec![keywords1, keywords2, keywords3],
-                products: product_results
-            };
-
-            // return the results
-            results
+            Ok(())
         }
 
 
-        // 27 🟢 Search Services By Keyword
+        // 86 🟢 Propose Admin (admin only)
+        // the new admin (an account, multisig or governance contract) must accept
+        // before anything changes, proposing again replaces the earlier proposal
         #[ink(message)]
-        pub fn search_services_by_keyword (&self,
-            keywords1: Vec<u8>,
-            keywords2: Vec<u8>,
-            keywords3: Vec<u8>
-        ) -> ServiceSearchResults {
-            // set up search targets
-            let targetvecu81 = keywords1.clone();
-            let target_string1 = String::from_utf8(targetvecu81).unwrap_or_default();
-            let targetvecu82 = keywords2.clone();
-            let target_string2 = String::from_utf8(targetvecu82).unwrap_or_default();
-            let targetvecu83 = keywords3.clone();
-            let target_string3 = String::from_utf8(targetvecu83).unwrap_or_default();
-
-            // set up return structures
-            let mut service_results = <Vec<Service>>::default();
-
-            // iterate over all_sellers: StorageVec<AccountId> to find matching results
-            if self.all_sellers.len() > 0 {
-                for i in 0..self.all_sellers.len() {
-                    let seller = self.all_sellers.get(i).unwrap();
-                    // get the seller's services
-                    let seller_services = self.account_seller_services.get(seller).unwrap_or_default();
-                    for item in seller_services.hashvector.iter() {
-                        // get the details
-                        let details = self.service_details.get(item).unwrap_or_default();
-                        // check to see if the keywords are there
-                        let title_string = String::from_utf8(details.title.clone()).unwrap_or_default();
-                        let seller_string = String::from_utf8(details.seller_name.clone()).unwrap_or_default();
-                        let category_string = String::from_utf8(details.category.clone()).unwrap_or_default();
-                        let description_string = String::from_utf8(details.description.clone()).unwrap_or_default();
-                        let location_string = String::from_utf8(details.service_location.clone()).unwrap_or_default();
-
-                        // if the target_string is in the details
-                        if (title_string.contains(&target_string1) || seller_string.contains(&target_string1) ||
-                        category_string.contains(&target_string1) || description_string.contains(&target_string1) ||
-                        location_string.contains(&target_string1)) 
-                        && (title_string.contains(&target_string2) || seller_string.contains(&target_string2) ||
-                        category_string.contains(&target_string2) || description_string.contains(&target_string2) ||
-                        location_string.contains(&target_string2)) 
-                        && (title_string.contains(&target_string3) || seller_string.contains(&target_string3) ||
-                        category_string.contains(&target_string3) || description_string.contains(&target_string3) ||
-                        location_string.contains(&target_string3)) {
-                            // add it to the results vector
-                            service_results.push(details);
-                        }
-                        // continue iterating over this seller's services
-                    }
-                    // continue iterating over the other sellers
-                }
+        pub fn propose_admin (&mut self,
+            proposed_admin: AccountId
+        ) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if caller != self.admin {
+                return Err(Error::NotAdmin);
             }
+            self.pending_admin = Some(proposed_admin);
 
-            // package the results
-            let results = ServiceSearchResults {
-                search: vec![keywords1, keywords2, keywords3],
-                services: service_results
-            };
+            // EMIT EVENT AdminProposed
+            Self::env().emit_event(AdminProposed {
+                admin: caller,
+                proposed_admin,
+            });
 
-            // return the results
-            results
+            Ok(())
         }
-        
-        
-        // 28 🟢 Search Stores by Keyword
-        #[ink(message)]
-        pub fn search_stores_by_keyword (&self,
-            keywords1: Vec<u8>,
-            keywords2: Vec<u8>,
-            keywords3: Vec<u8>
-        ) -> StoreSearchResults {
-            // set up search targets
-            let targetvecu81 = keywords1.clone();
-            let target_string1 = String::from_utf8(targetvecu81).unwrap_or_default();
-            let targetvecu82 = keywords2.clone();
-            let target_string2 = String::from_utf8(targetvecu82).unwrap_or_default();
-            let targetvecu83 = keywords3.clone();
-            let target_string3 = String::from_utf8(targetvecu83).unwrap_or_default();
-
-            // set up return structures
-            let mut store_results = <Vec<SellerProfile>>::default();
 
-            // iterate over all_sellers: StorageVec<AccountId> to find matching results
-            if self.all_sellers.len() > 0 {
-                for i in 0..self.all_sellers.len() {
-                    let acct = self.all_sellers.get(i).unwrap();
-                    // get the profile
-                    let profile = self.account_profile_seller.get(acct).unwrap_or_default();
-                    
-                    // check to see if the keywords are there
-                    let name_string = String::from_utf8(profile.seller_name.clone()).unwrap_or_default();
-                    let description_string = String::from_utf8(profile.store_description.clone()).unwrap_or_default();
-                    let location_string = String::from_utf8(profile.seller_location.clone()).unwrap_or_default();
 
-                    // if the target_string is in the profile
-                    if (name_string.contains(&target_string1) || description_string.contains(&target_string1) ||
-                    location_string.contains(&target_string1))
-                    && (name_string.contains(&target_string2) || description_string.contains(&target_string2) ||
-                    location_string.contains(&target_string2))
-                    && (name_string.contains(&target_string3) || description_string.contains(&target_string3) ||
-                    location_string.contains(&target_string3)) {
-                        // add it to the results vector
-                        store_results.push(profile);
-                    }
-                }
-                //continue iterating on the other sellers
+        // 87 🟢 Accept Admin (proposed admin only)
+        #[ink(message)]
+        pub fn accept_admin (&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if self.pending_admin != Some(caller) {
+                return Err(Error::NotPendingAdmin);
             }
+            let previous_admin = self.admin;
+            self.admin = caller;
+            self.pending_admin = None;
+
+            // EMIT EVENT AdminTransferred
+            Self::env().emit_event(AdminTransferred {
+                previous_admin,
+                new_admin: caller,
+            });
 
-            // package the results
-            let results = StoreSearchResults {
-                search: vec![keywords1, keywords2, keywords3],
-                stores: store_results
-            };
+            Ok(())
+        }
 
-            // return the results
-            results
+
+        // 88 🟢 View Admin Settings
+        // returns the admin, the proposed admin (if any) and both pause flags
+        #[ink(message)]
+        pub fn view_admin_settings (&self) -> (AccountId, Option<AccountId>, bool, bool) {
+            (self.admin, self.pending_admin, self.paused_all, self.paused_checkout)
         }
 
 
-        // 29 🟢 View My Orders
+        // 89 🟢 Set Abandon Penalty (admin only)
+        // penalty is in basis points of the order price (100 = 1%), taken from a bonded
+        // seller's bond and paid to the buyer when refund_unshipped_order is called, 0 turns it off
         #[ink(message)]
-        pub fn view_my_orders (&self) -> ViewBuyerOrders {
-            // set the caller
+        pub fn set_abandon_penalty (&mut self,
+            abandon_penalty_bps: u128
+        ) -> Result<(), Error> {
             let caller = Self::env().caller();
-            // set up return structures
-            let mut myorders = <Vec<Order>>::default();
-            
-            // get the buyer's completed Orders...
-            // get the vector of order ids from storage
-            let order_ids = self.account_buyer_orders.get(caller).unwrap_or_default();
-            // get the details of each order and add them to the Vec<Order> myorders
-            for id in order_ids.hashvector.iter() {
-                // order_details: Mapping<Hash, Order>
-                let orderdetails = self.order_details.get(id).unwrap_or_default();
-                myorders.push(orderdetails);
+            if caller != self.admin {
+                return Err(Error::NotAdmin);
             }
+            if abandon_penalty_bps > 10000 {
+                return Err(Error::FeeTooHigh);
+            }
+            self.abandon_penalty_bps = abandon_penalty_bps;
 
-            // package the results
-            let my_orders = ViewBuyerOrders {
-                buyer: caller,
-                carts: myorders,
-            };
+            // EMIT EVENT AbandonPenaltyUpdated
+            Self::env().emit_event(AbandonPenaltyUpdated {
+                admin: caller,
+                abandon_penalty_bps,
+            });
 
-            // return the results
-            my_orders
+            Ok(())
         }
-        
 
-        // 30 🟢 View My (Buyer) Account
-        // Front end: if the product/service inventory is zero, note as unavailable
-        #[ink(message)]
-        pub fn view_my_buyer_account (&self) -> ViewBuyerAccount {
-            // set the caller
-            let caller = Self::env().caller();
-            // get the buyer profile from account_profile_buyer: Mapping<AccountId, BuyerProfile>
-            let buyerprofile = self.account_profile_buyer.get(caller).unwrap_or_default();
 
-            // set up return structures
-            let mut bookmarkedstores = <Vec<SellerProfile>>::default();
-            let mut downloads = <Vec<Download>>::default();
-            let mut myorders = <Vec<Order>>::default();
+        // END OF MESSAGE LIST
 
-            // get bookmarked store accounts from account_store_bookmarks: Mapping<AccountId, AccountVector>
-            let store_accounts = self.account_store_bookmarks.get(caller).unwrap_or_default();
-            // for each seller account get the account_profile_seller: Mapping<AccountId, SellerProfile>
-            for seller in store_accounts.accountvector.iter() {
-                let profile = self.account_profile_seller.get(seller).unwrap_or_default();
-                // add that profile to the bookmarkedstores vector of profiles
-                bookmarkedstores.push(profile);
+
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // >>>>>>>>>>>>>>>>>>>>>>>>>> HELPER FUNCTIONS <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+
+        // get the seller, current price and inventory for a product or service
+        fn listing_details (&self, item_id: Hash) -> Option<(AccountId, Balance, u128)> {
+            if let Some(details) = self.product_details.get(item_id) {
+                return Some((details.seller_account, details.price, details.inventory));
+            }
+            if let Some(details) = self.service_details.get(item_id) {
+                return Some((details.seller_account, details.price, details.inventory));
             }
+            None
+        }
 
-            // get the digital product ids
-            let digital_ids = self.account_owned_digital_items.get(caller).unwrap_or_default();
-            // for each digital item owned, get the details and create the Download struct
-            for id in digital_ids.hashvector.iter() {
-                // get the product details
-                let details = self.product_details.get(id).unwrap_or_default();
-                // create the Download struct for that id
-                let download_item = Download {
-                    product_id: details.product_id,
-                    title: details.title,
-                    brand: details.brand,
-                    seller_account: details.seller_account,
-                    seller_name: details.seller_name,
-                    description: details.description,
-                    photo: details.photo_or_youtube_link1,
-                    more_info: details.more_info_link,
-                    file_url: details.digital_file_url,
-                };
-                // add the download_item to the downloads vector
-                downloads.push(download_item);
+        // split a cart into the items that can be bought right now and the items
+        // that would be dropped, and total the price of the items that can be bought
+        fn sort_cart_items (&self, cart: &UnpaidCart) -> (Vec<(Hash, u128)>, Vec<DroppedCartItem>, Balance) {
+            let mut final_cart_items = <Vec<(Hash, u128)>>::default();
+            let mut dropped_items = <Vec<DroppedCartItem>>::default();
+            let mut carttotal: Balance = 0;
+            for (item, number) in &cart.cart_items {
+                match self.listing_details(*item) {
+                    // if the item has enough inventory, add it to the official cart items
+                    Some((_, item_price, item_inventory)) if item_inventory >= *number => {
+                        carttotal = carttotal.saturating_add(item_price.saturating_mul(*number));
+                        final_cart_items.push((*item, *number));
+                    }
+                    Some(_) => {
+                        dropped_items.push(DroppedCartItem {
+                            item_id: *item,
+                            quantity: *number,
+                            reason: 1,
+                        });
+                    }
+                    None => {
+                        dropped_items.push(DroppedCartItem {
+                            item_id: *item,
+                            quantity: *number,
+                            reason: 2,
+                        });
+                    }
+                }
             }
+            (final_cart_items, dropped_items, carttotal)
+        }
 
-            // get the buyer's Orders...
-            // get the vector of order ids from storage
-            let order_ids = self.account_buyer_orders.get(caller).unwrap_or_default();
-            // get the details of each order and add them to the Vec<Order> myorders
-            for id in order_ids.hashvector.iter() {
-                // order_details: Mapping<Hash, Order>
-                let orderdetails = self.order_details.get(id).unwrap_or_default();
-                myorders.push(orderdetails);
+        // add a platform fee to the treasury's withdrawable balance and today's fee total
+        fn accrue_platform_fee (&mut self, fee: Balance) {
+            if fee > 0 {
+                self.fees_available = self.fees_available.saturating_add(fee);
+                self.fees_accrued_total = self.fees_accrued_total.saturating_add(fee);
+                // fees are tracked per day, keep the 365 most recent days
+                let day: u64 = self.env().block_timestamp().saturating_div(86400000);
+                if !self.fee_days.contains(&day) {
+                    if self.fee_days.len() > 364 {
+                        let oldest = self.fee_days.remove(0);
+                        self.fees_by_day.remove(oldest);
+                    }
+                    self.fee_days.push(day);
+                }
+                let today = self.fees_by_day.get(day).unwrap_or_default();
+                self.fees_by_day.insert(day, &today.saturating_add(fee));
             }
+        }
 
-            // package the results
-            let my_account = ViewBuyerAccount {
-                buyer: buyerprofile,
-                bookmarked_stores: bookmarkedstores,
-                digital_downloads: downloads,
-                orders: myorders,
-            };
+        // put the quantity on an order back into the inventory of its product or service
+        // reason code is the same as the InventoryAdjusted event
+        fn restore_inventory (&mut self, order: &Order, reason: u8) {
+            let inventory: u128;
+            if self.product_details.contains(order.item_id) {
+                let mut details = self.product_details.get(order.item_id).unwrap_or_default();
+                details.inventory = details.inventory.saturating_add(order.quantity);
+                inventory = details.inventory;
+                self.product_details.insert(order.item_id, &details);
+            }
+            else if self.service_details.contains(order.item_id) {
+                let mut details = self.service_details.get(order.item_id).unwrap_or_default();
+                details.inventory = details.inventory.saturating_add(order.quantity);
+                inventory = details.inventory;
+                self.service_details.insert(order.item_id, &details);
+            }
+            else {
+                // the listing was removed, there is nothing to restock
+                return;
+            }
 
-            // return the results
-            my_account
+            // EMIT EVENT InventoryAdjusted
+            Self::env().emit_event(InventoryAdjusted {
+                item_id: order.item_id,
+                seller: order.seller,
+                order_id: order.order_id,
+                quantity: order.quantity,
+                inventory,
+                reason,
+            });
         }
 
+        // take up to amount out of a seller's bond and return what was taken
+        // the caller decides who gets it
+        fn slash_bond (&mut self, seller: AccountId, order_id: Hash, amount: Balance) -> Balance {
+            let mut profile = self.account_profile_seller.get(seller).unwrap_or_default();
+            let slashed: Balance = amount.min(profile.bond);
+            if slashed == 0 {
+                return 0;
+            }
+            profile.bond = profile.bond.saturating_sub(slashed);
+            if profile.bond == 0 {
+                profile.bond_backed = false;
+            }
+            self.account_profile_seller.insert(seller, &profile);
+
+            // EMIT EVENT BondSlashed
+            Self::env().emit_event(BondSlashed {
+                seller,
+                order_id,
+                amount: slashed,
+                bond_remaining: profile.bond,
+            });
 
-        // 31 🟢 View My (Unpaid) Cart
-        #[ink(message)]
-        pub fn view_my_cart (&self) -> ViewUnpaidCart {
-            // set the caller
-            let caller = Self::env().caller();
-            // get the callers current unpaid cart from account_current_cart: Mapping<AccountId, UnpaidCart>
-            let current_cart = self.account_current_cart.get(caller).unwrap_or_default();
+            slashed
+        }
 
-            // set up return structures
-            let mut cartproducts = <Vec<UnpaidCartProduct>>::default();
-            let mut cartservices = <Vec<UnpaidCartService>>::default();
-            let mut carttotal_products: Balance = 0;
-            let mut carttotal_services: Balance = 0;
+        // when the problem window on an order starts
+        // physical and digital orders start when delivered, services start when the seller marks
+        // them completed or else at the scheduled date, and a service with neither starts when ordered
+        fn problem_window_start (&self, details: &Order) -> u64 {
+            if !details.is_service {
+                return details.time_delivered;
+            }
+            if details.time_service_completed > 0 {
+                return details.time_service_completed;
+            }
+            if details.service_date > 0 {
+                return details.service_date;
+            }
+            details.order_timestamp
+        }
 
-            // each item in current_cart.cart_items looks like (Hash, u128) meaning (itemid, quantity)
-            // for each item, determine product or service
-            for (item, number) in current_cart.cart_items.iter() {
-                if self.product_details.contains(item) {
-                    // get the product details
-                    let productdetails = self.product_details.get(item).unwrap_or_default();
-                    let zenobuyers = productdetails.zeno_buyers.len().try_into().unwrap();
+        // the status moves an order is allowed to make
+        fn can_transition (from: OrderStatus, to: OrderStatus) -> bool {
+            matches!((from, to),
+                (OrderStatus::Awaiting, OrderStatus::Shipped)
+                | (OrderStatus::Awaiting, OrderStatus::Refused)
+                | (OrderStatus::Awaiting, OrderStatus::Cancelled)
+                | (OrderStatus::Shipped, OrderStatus::Delivered)
+                | (OrderStatus::Shipped, OrderStatus::Complete)
+                | (OrderStatus::Shipped, OrderStatus::Problem)
+                | (OrderStatus::Delivered, OrderStatus::Complete)
+                | (OrderStatus::Delivered, OrderStatus::Problem)
+                | (OrderStatus::Problem, OrderStatus::Complete)
+                | (OrderStatus::Problem, OrderStatus::ReturnAuthorized)
+                | (OrderStatus::ReturnAuthorized, OrderStatus::ReturnShipped)
+                | (OrderStatus::ReturnAuthorized, OrderStatus::Problem)
+                | (OrderStatus::ReturnShipped, OrderStatus::Complete)
+            )
+        }
 
-                    // make the UnpaidCartProduct structure
-                    let unpaidproduct = UnpaidCartProduct {
-                        product_id: *item,
-                        quantity: *number,
-                        digital: productdetails.digital,
-                        title: productdetails.title,
-                        price: productdetails.price,
-                        brand: productdetails.brand,
-                        seller_account: productdetails.seller_account,
-                        seller_name: productdetails.seller_name,
-                        photo_or_youtube_link1: productdetails.photo_or_youtube_link1,
-                        inventory: productdetails.inventory, 
-                        delivery_info: productdetails.delivery_info,
-                        product_location: productdetails.product_location,
-                        zeno_percent: productdetails.zeno_percent,
-                        zeno_buyers: zenobuyers
-                    };
+        // move an order to a new status and note it in the order's timeline
+        // every status change goes through here so illegal moves are rejected in one place
+        fn transition (&self, details: &mut Order, to: OrderStatus, actor: AccountId, note: &[u8]) -> Result<(), Error> {
+            if !Self::can_transition(details.order_status, to) {
+                return Err(Error::IllegalTransition);
+            }
+            details.order_status = to;
+            self.add_timeline_entry(details, actor, note);
+            Ok(())
+        }
 
-                    // add that to the cartproducts vector
-                    cartproducts.push(unpaidproduct);
+        // add an entry for the order's current status to its timeline, keeping the 20 most recent
+        fn add_timeline_entry (&self, details: &mut Order, actor: AccountId, note: &[u8]) {
+            if details.timeline.len() > 19 {
+                details.timeline.remove(0);
+            }
+            details.timeline.push(StatusChange {
+                status: details.order_status,
+                timestamp: self.env().block_timestamp(),
+                actor,
+                note: note.to_vec(),
+            });
+        }
 
-                    // add the price to the cart total for products IF there is enough inventory
-                    if productdetails.inventory >= *number {
-                        carttotal_products = carttotal_products.saturating_add(productdetails.price.saturating_mul(*number));
-                    }
-                    
+        // clear the escrow for an order that is being refunded, keeping up to refund for the buyer
+        // and paying the rest out to the seller, returns how much of the refund escrow covered
+        fn refund_from_escrow (&mut self, details: &Order, refund: Balance) -> Balance {
+            let escrowed = self.order_escrow.get(details.order_id).unwrap_or_default();
+            let from_escrow: Balance = refund.min(escrowed);
+            if escrowed > 0 {
+                self.remove_from_escrow(details.seller, details.order_id);
+                let remainder = escrowed.saturating_sub(from_escrow);
+                if remainder > 0 {
+                    self.pay_out_order(details, remainder);
                 }
-                else {
-                    if self.service_details.contains(item) {
-                        // get the service details
-                        let servicedetails = self.service_details.get(item).unwrap_or_default();
-                        let zenobuyers = servicedetails.zeno_buyers.len().try_into().unwrap();
+            }
+            from_escrow
+        }
 
-                        // make the UnpaidCartService structure
-                        let unpaidservice = UnpaidCartService {
-                            service_id: *item,
-                            quantity: *number,
-                            online: servicedetails.online,
-                            title: servicedetails.title,
-                            price: servicedetails.price,
-                            seller_account: servicedetails.seller_account,
-                            seller_name: servicedetails.seller_name,
-                            photo_or_youtube_link1: servicedetails.photo_or_youtube_link1,
-                            booking_link: servicedetails.booking_link,
-                            inventory: servicedetails.inventory, 
-                            service_location: servicedetails.service_location,
-                            zeno_percent: servicedetails.zeno_percent,
-                            zeno_buyers: zenobuyers
-                        };
+        // give the seller back whatever they sent for a pending partial refund offer
+        // the part of the offer escrow would cover never left escrow
+        fn return_refund_offer (&mut self, details: &mut Order) {
+            if details.refund_offered > 0 {
+                let escrowed = self.order_escrow.get(details.order_id).unwrap_or_default();
+                let seller_funded: Balance = details.refund_offered.saturating_sub(escrowed);
+                self.credit_account(details.seller, details.order_id, seller_funded, CreditReason::Refund);
+                details.refund_offered = 0;
+            }
+        }
 
-                        // add that to the cartservices vector
-                        cartservices.push(unpaidservice);
+        // credit a refund to the buyer and resolve the problem order as refunded
+        // resolution is Refunded for a plain refund or ReturnedAndRefunded when the items came back first
+        fn finish_refund (&mut self, mut details: Order, amount: Balance, resolution: Resolution, refunded_by: AccountId) -> Result<(), Error> {
+            let note: &[u8] = if resolution == Resolution::ReturnedAndRefunded { b"returned and refunded" } else { b"refunded" };
+            let order_id = details.order_id;
+            let seller = details.seller;
+            let buyer = details.buyer;
+            self.credit_account(buyer, order_id, amount, CreditReason::Refund);
+
+            // update order_details: Mapping<Hash, Order>
+            details.refunded_amount = details.refunded_amount.saturating_add(amount);
+            details.resolution = resolution;
+            self.transition(&mut details, OrderStatus::Complete, refunded_by, note)?;
+            self.order_details.insert(order_id, &details);
+
+            // move the order from orders_4problem to orders_3resolved
+            let mut problem = self.account_seller_orders_4problem.get(seller).unwrap_or_default();
+            let mut resolved = self.account_seller_orders_3resolved.get(seller).unwrap_or_default();
+            // if the seller's resolved list is full, kick out the oldest
+            if resolved.hashvector.len() > 69 {
+                resolved.hashvector.remove(0);
+            }
+            problem.hashvector.retain(|value| *value != order_id);
+            resolved.hashvector.push(order_id);
+            // update the maps
+            self.account_seller_orders_4problem.insert(seller, &problem);
+            self.account_seller_orders_3resolved.insert(seller, &resolved);
 
-                        // add the price to the cart total for services IF there is enough invetory
-                        if servicedetails.inventory >= *number {
-                            carttotal_services = carttotal_services.saturating_add(servicedetails.price.saturating_mul(*number));
-                        }
+            // update Buyer profile
+            // account_profile_buyer: Mapping<AccountId, BuyerProfile>
+            let mut buyerprofile = self.account_profile_buyer.get(buyer).unwrap_or_default();
+            buyerprofile.total_resolved = buyerprofile.total_resolved.saturating_add(1);
+            self.account_profile_buyer.insert(buyer, &buyerprofile);
 
-                    }
-                }
+            // update Seller profile
+            // account_profile_seller: Mapping<AccountId, SellerProfile>
+            let mut sellerprofile = self.account_profile_seller.get(seller).unwrap_or_default();
+            sellerprofile.total_resolved = sellerprofile.total_resolved.saturating_add(1);
+            self.account_profile_seller.insert(seller, &sellerprofile);
+
+            // EMIT EVENT OrderRefunded
+            Self::env().emit_event(OrderRefunded {
+                seller,
+                buyer,
+                order_id,
+                order_status: OrderStatus::Complete,
+                problem: details.problem,
+                resolution,
+                refunded_amount: details.refunded_amount,
+            });
+
+            Ok(())
+        }
+
+        // when anyone can release the escrow on a shipped or delivered order
+        // an order that was never marked delivered stays locked until the buyer's
+        // window to report it not received has passed
+        fn escrow_release_time (&self, order: &Order) -> u64 {
+            let release_time = order.time_shipped.saturating_add(self.escrow_timeout);
+            if order.resolution == Resolution::Denied && order.arbitration == Arbitration::None {
+                // a denied problem stays locked while the buyer can still escalate it
+                return release_time.max(order.time_denied.saturating_add(ESCALATION_WINDOW));
             }
+            if order.order_status == OrderStatus::Shipped {
+                return release_time.max(order.delivery_deadline.saturating_add(order.dispute_window));
+            }
+            // a delivered order stays locked while the buyer can still report a problem
+            if order.order_status == OrderStatus::Delivered {
+                return release_time.max(order.time_delivered.saturating_add(order.dispute_window));
+            }
+            release_time
+        }
 
-            // the cart total is the total of all items in the cart for which there
-            // is sufficient inventory to fulfil the order if you order right now
-            let carttotal: Balance = carttotal_products.saturating_add(carttotal_services);
-            
-            // package the results
-            let my_cart = ViewUnpaidCart {
-                buyer: caller,
-                cart_total: carttotal,
-                total_items: current_cart.total_items,
-                cart_products: cartproducts,
-                cart_services: cartservices
-            };
+        // remove an order from order_escrow and from the seller's escrow list
+        fn remove_from_escrow (&mut self, seller: AccountId, order_id: Hash) {
+            self.order_escrow.remove(order_id);
+            let mut seller_escrow = self.account_seller_escrow.get(seller).unwrap_or_default();
+            seller_escrow.hashvector.retain(|value| *value != order_id);
+            self.account_seller_escrow.insert(seller, &seller_escrow);
+        }
 
-            // return the results
-            my_cart
+        // release whatever is held in escrow for an order to the seller and zeno buyers
+        fn release_order_escrow (&mut self, details: &Order, released_by: AccountId) {
+            let amount = self.order_escrow.get(details.order_id).unwrap_or_default();
+            if amount > 0 {
+                self.remove_from_escrow(details.seller, details.order_id);
+                self.pay_out_order(details, amount);
+
+                // EMIT EVENT EscrowReleased
+                Self::env().emit_event(EscrowReleased {
+                    order_id: details.order_id,
+                    seller: details.seller,
+                    released_by,
+                    amount,
+                    timestamp: self.env().block_timestamp(),
+                });
+            }
         }
 
+        // mark a shipped or delivered order complete, release its escrow and update the profiles
+        fn complete_order (&mut self, mut details: Order, completed_by: AccountId) -> Result<(), Error> {
+            let rightnow = self.env().block_timestamp();
+            let order_id = details.order_id;
+            let seller = details.seller;
+            let buyer = details.buyer;
+            let was_shipped = details.order_status == OrderStatus::Shipped;
+
+            // update order_details: Mapping<Hash, Order>
+            self.transition(&mut details, OrderStatus::Complete, completed_by, b"completed")?;
+            if details.time_delivered == u64::default() {
+                details.time_delivered = rightnow;
+            }
+            self.order_details.insert(order_id, &details);
 
-        // 32 🟢 Go To Store
-        #[ink(message)]
-        pub fn go_to_store (&self,
-            seller: AccountId
-        ) -> ViewStore {
-            // get the seller's profile from account_profile_seller: Mapping<AccountId, SellerProfile>
-            let store_owner = self.account_profile_seller.get(seller).unwrap_or_default();
-            // set up return structures
-            let mut store_products = <Vec<PublicProduct>>::default();
-            let mut store_services = <Vec<Service>>::default();
+            // move the order from orders_1shipped or orders_2delivered to orders_3resolved
+            if was_shipped {
+                let mut shipped = self.account_seller_orders_1shipped.get(seller).unwrap_or_default();
+                shipped.hashvector.retain(|value| *value != order_id);
+                self.account_seller_orders_1shipped.insert(seller, &shipped);
+            }
+            else {
+                let mut delivered = self.account_seller_orders_2delivered.get(seller).unwrap_or_default();
+                delivered.hashvector.retain(|value| *value != order_id);
+                self.account_seller_orders_2delivered.insert(seller, &delivered);
+            }
+            let mut resolved = self.account_seller_orders_3resolved.get(seller).unwrap_or_default();
+            // if the seller's resolved list is full, kick out the oldest
+            if resolved.hashvector.len() > 69 {
+                resolved.hashvector.remove(0);
+            }
+            resolved.hashvector.push(order_id);
+            self.account_seller_orders_3resolved.insert(seller, &resolved);
 
-            // get the seller's products from account_seller_products: Mapping<AccountId, HashVector>
-            let product_ids = self.account_seller_products.get(seller).unwrap_or_default();
-            for id in product_ids.hashvector.iter() {
-                // get the product details struct and add it to the store_products vector
-                let details = self.product_details.get(id).unwrap_or_default();
-                // make the public product structure
-                let public_product = PublicProduct {
-                    product_id: details.product_id,
-                    digital: details.digital,
-                    title: details.title,
-                    price: details.price,
-                    brand: details.brand,
-                    category: details.category,
-                    seller_account: details.seller_account,
-                    seller_name: details.seller_name,
-                    description: details.description,
-                    review_average: details.review_average,
-                    review_count: details.review_count,
-                    inventory: details.inventory, 
-                    photo_or_youtube_link1: details.photo_or_youtube_link1, 
-                    photo_or_youtube_link2: details.photo_or_youtube_link2,
-                    photo_or_youtube_link3: details.photo_or_youtube_link3,
-                    more_info_link: details.more_info_link,
-                    delivery_info: details.delivery_info,
-                    product_location: details.product_location,
-                    zeno_percent: details.zeno_percent,
-                    zeno_buyers: details.zeno_buyers
-                };
-                store_products.push(public_product);
+            // update Buyer profile
+            // account_profile_buyer: Mapping<AccountId, BuyerProfile>
+            let mut buyerprofile = self.account_profile_buyer.get(buyer).unwrap_or_default();
+            if was_shipped {
+                buyerprofile.total_delivered = buyerprofile.total_delivered.saturating_add(1);
             }
+            buyerprofile.total_complete = buyerprofile.total_complete.saturating_add(1);
+            self.account_profile_buyer.insert(buyer, &buyerprofile);
 
-            // get the seller's services from account_seller_services: Mapping<AccountId, HashVector> 
-            let service_ids = self.account_seller_services.get(seller).unwrap_or_default();
-            for id in service_ids.hashvector.iter() {
-                // get the service details struct and add it to the store_service vector
-                let servicedetails = self.service_details.get(id).unwrap_or_default();
-                store_services.push(servicedetails);
+            // update Seller profile
+            // account_profile_seller: Mapping<AccountId, SellerProfile>
+            let mut sellerprofile = self.account_profile_seller.get(seller).unwrap_or_default();
+            if was_shipped {
+                sellerprofile.total_delivered = sellerprofile.total_delivered.saturating_add(1);
             }
+            sellerprofile.total_complete = sellerprofile.total_complete.saturating_add(1);
+            self.account_profile_seller.insert(seller, &sellerprofile);
+
+            // release any payment still held in escrow
+            self.release_order_escrow(&details, completed_by);
+
+            // EMIT EVENT OrderCompleted
+            Self::env().emit_event(OrderCompleted {
+                seller,
+                buyer,
+                order_id,
+                order_status: OrderStatus::Complete,
+                timestamp: rightnow,
+            });
 
-            // package the results
-            let view_store = ViewStore {
-                owner: store_owner,
-                products: store_products,
-                services: store_services
+            Ok(())
+        }
+
+        // credit the seller and the zeno buyers for an order out of the contract balance
+        fn pay_out_order (&mut self, details: &Order, amount: Balance) {
+            let seller = details.seller;
+            // a partial payout (after a partial refund) carries the same share of each fee as the full order
+            let prorate = |fee: Balance| -> Balance {
+                if amount < details.total_order_price {
+                    fee.saturating_mul(amount).checked_div(details.total_order_price).unwrap_or_default()
+                }
+                else {
+                    fee
+                }
             };
+            let zeno_total: Balance = prorate(details.zeno_total).min(amount);
+            let platform_fee: Balance = prorate(details.platform_fee).min(amount.saturating_sub(zeno_total));
+            let protection_fee: Balance = prorate(details.protection_fee).min(amount.saturating_sub(zeno_total).saturating_sub(platform_fee));
+            // zeno buyers come from the listing, the zeno settings from the order
+            let (zeno_buyers, _, _) = self.listing_zeno(details.item_id);
+            let (zeno_max_buyers, zeno_curve) = (details.zeno_max_buyers, details.zeno_curve);
+            let seller_payout: Balance = amount.saturating_sub(zeno_total).saturating_sub(platform_fee).saturating_sub(protection_fee);
+
+            // credit the seller
+            self.credit_account(seller, details.order_id, seller_payout, CreditReason::Sale);
+            // set aside the platform fee for the treasury
+            self.accrue_platform_fee(platform_fee);
+            // and the protection slice for the buyer protection pool
+            self.protection_pool = self.protection_pool.saturating_add(protection_fee);
+            self.protection_funded_total = self.protection_funded_total.saturating_add(protection_fee);
+
+            // If the zeno_total is not zero, initiate the zeno payouts
+            if zeno_total > 0 {
+                // credit all zeno buyers their slot's share
+                let shares = Self::zeno_slot_shares(zeno_total, zeno_max_buyers, zeno_curve);
+                let mut remainder: Balance = zeno_total;
+                for (n, (affiliate, payment)) in zeno_buyers.iter().zip(shares.iter()).enumerate() {
+                    self.credit_account(*affiliate, details.order_id, *payment, CreditReason::Zeno);
+                    self.record_zeno_payout(*affiliate, details, n, *payment);
+                    remainder = remainder.saturating_sub(*payment);
+                }
+                // credit the seller any remainder from the zeno payouts
+                self.credit_account(seller, details.order_id, remainder, CreditReason::Sale);
+            }
+        }
 
-            // return the results
-            view_store
+        // get the zeno buyers, maximum zeno buyers and zeno curve for a product or service
+        fn listing_zeno (&self, item_id: Hash) -> (Vec<AccountId>, u128, u8) {
+            if let Some(details) = self.product_details.get(item_id) {
+                return (details.zeno_buyers, details.zeno_max_buyers, details.zeno_curve);
+            }
+            if let Some(details) = self.service_details.get(item_id) {
+                return (details.zeno_buyers, details.zeno_max_buyers, details.zeno_curve);
+            }
+            (<Vec<AccountId>>::default(), 0, 0)
         }
 
+        // split a zeno total across zeno slots, first slot first
+        // any share belonging to an empty slot goes back to the seller
+        fn zeno_slot_shares (zeno_total: Balance, max_buyers: u128, curve: u8) -> Vec<Balance> {
+            let mut shares = <Vec<Balance>>::default();
+            // the sum of the linear weights max, max-1, ... 1
+            let linear_sum = max_buyers.saturating_mul(max_buyers.saturating_add(1)).saturating_div(2);
+            for n in 0..max_buyers {
+                let share: Balance = match curve {
+                    // linear, slot n gets (max - n) parts of the total
+                    1 => zeno_total.saturating_mul(max_buyers.saturating_sub(n)).checked_div(linear_sum).unwrap_or_default(),
+                    // equal split
+                    2 => zeno_total.checked_div(max_buyers).unwrap_or_default(),
+                    // halving, slot n gets 1/2^(n+1) of the total
+                    _ => {
+                        let p: u32 = n.saturating_add(1).try_into().unwrap_or(u32::MAX);
+                        zeno_total.checked_div(2u128.saturating_pow(p)).unwrap_or_default()
+                    }
+                };
+                shares.push(share);
+            }
+            shares
+        }
 
-        // 33 🟢 View My Seller Account - PROFILE, PRODUCTS and SERVICES
-        #[ink(message)]
-        pub fn view_my_seller_profile (&self) -> ViewSellerAccount {
-            // set the caller
-            let caller = Self::env().caller();
-            let store_owner = self.account_profile_seller.get(caller).unwrap_or_default();
-            let mut store_products = <Vec<Product>>::default();
-            let mut store_services = <Vec<Service>>::default();
+        // remember that an account holds a zeno slot on an item, keep the 400 most recent items
+        fn record_zeno_item (&mut self, affiliate: AccountId, item_id: Hash) {
+            let mut items = self.account_zeno_items.get(affiliate).unwrap_or_default();
+            if !items.hashvector.contains(&item_id) {
+                if items.hashvector.len() > 399 {
+                    let oldest = items.hashvector.remove(0);
+                    self.account_zeno_item_earnings.remove((affiliate, oldest));
+                }
+                items.hashvector.push(item_id);
+                self.account_zeno_items.insert(affiliate, &items);
+            }
+        }
 
-            // get the seller's products from account_seller_products: Mapping<AccountId, HashVector>
-            let product_ids = self.account_seller_products.get(caller).unwrap_or_default();
-            for id in product_ids.hashvector.iter() {
-                // get the product details struct and add it to the store_products vector
-                let productdetails = self.product_details.get(id).unwrap_or_default();
-                store_products.push(productdetails);
+        // add a zeno payment to the affiliate's per item and lifetime earnings
+        fn record_zeno_payout (&mut self, affiliate: AccountId, details: &Order, slot: usize, amount: Balance) {
+            if amount > 0 {
+                let item_earned = self.account_zeno_item_earnings.get((affiliate, details.item_id)).unwrap_or_default();
+                self.account_zeno_item_earnings.insert((affiliate, details.item_id), &item_earned.saturating_add(amount));
+                let lifetime = self.account_zeno_earnings_total.get(affiliate).unwrap_or_default();
+                self.account_zeno_earnings_total.insert(affiliate, &lifetime.saturating_add(amount));
+
+                // EMIT EVENT ZenoPayout
+                Self::env().emit_event(ZenoPayout {
+                    affiliate,
+                    item_id: details.item_id,
+                    order_id: details.order_id,
+                    slot: slot.saturating_add(1).try_into().unwrap(),
+                    amount,
+                });
             }
+        }
 
-            // get the seller's services from account_seller_services: Mapping<AccountId, HashVector> 
-            let service_ids = self.account_seller_services.get(caller).unwrap_or_default();
-            for id in service_ids.hashvector.iter() {
-                // get the service details struct and ad it to the store_service vector
-                let servicedetails = self.service_details.get(id).unwrap_or_default();
-                store_services.push(servicedetails);
+        // returns Paused if the admin has stopped the marketplace,
+        // or stopped checkout when the caller is placing an order
+        fn check_not_paused (&self, checkout: bool) -> Result<(), Error> {
+            if self.paused_all || (checkout && self.paused_checkout) {
+                return Err(Error::Paused);
             }
+            Ok(())
+        }
 
-            // return the results
-            let results = ViewSellerAccount {
-                owner: store_owner,
-                products: store_products,
-                services: store_services
-            };
-            
-            results
+        // add to an account's claimable balance, which they collect with withdraw
+        fn credit_account (&mut self, account: AccountId, order_id: Hash, amount: Balance, reason: CreditReason) {
+            if amount > 0 {
+                let balance = self.claimable_balance.get(account).unwrap_or_default();
+                self.claimable_balance.insert(account, &balance.saturating_add(amount));
+
+                // EMIT EVENT BalanceCredited
+                Self::env().emit_event(BalanceCredited {
+                    account,
+                    order_id,
+                    amount,
+                    reason,
+                });
+            }
         }
 
+    }
+    // END OF CONTRACT STORAGE
 
-        // 34 🟢 View My Seller Account - ORDERS - AWAITING
-        #[ink(message)]
-        pub fn view_my_seller_orders_awaiting (&self) -> Vec<Order> {
-            // set the caller
-            let caller = Self::env().caller();
-            // set up return structures
-            let mut store_orders = <Vec<Order>>::default();
 
-            // get the seller's orders from account_seller_orders: Mapping<AccountId, HashVector>
-            let order_ids0 = self.account_seller_orders_0awaiting.get(caller).unwrap_or_default();
-            for id in order_ids0.hashvector.iter() {
-                // get the order details and add it to the store_orders vector order_details: Mapping<Hash, Order>
-                let orderdetails = self.order_details.get(id).unwrap_or_default();
-                store_orders.push(orderdetails);
-            }
+    // TESTS >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+    // alice deploys the contract (admin and treasury), bob sells, charlie buys
 
-            // return the results
-            store_orders
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::test;
+        use ink::env::DefaultEnvironment;
+
+        const DAY: u64 = 86400000;
+
+        fn accounts() -> test::DefaultAccounts<DefaultEnvironment> {
+            test::default_accounts::<DefaultEnvironment>()
         }
 
-        // 35 🟢 View My Seller Account - ORDERS - SHIPPED
-        #[ink(message)]
-        pub fn view_my_seller_orders_shipped (&self) -> Vec<Order> {
-            // set the caller
-            let caller = Self::env().caller();
-            // set up return structures
-            let mut store_orders = <Vec<Order>>::default();
+        fn set_caller(account: AccountId) {
+            test::set_caller::<DefaultEnvironment>(account);
+            test::set_value_transferred::<DefaultEnvironment>(0);
+        }
 
-            let order_ids1 = self.account_seller_orders_1shipped.get(caller).unwrap_or_default();
-            for id in order_ids1.hashvector.iter() {
-                // get the order details and add it to the store_orders vector order_details: Mapping<Hash, Order>
-                let orderdetails = self.order_details.get(id).unwrap_or_default();
-                store_orders.push(orderdetails);
-            }
-            
-            // return the results
-            store_orders
+        fn set_now(timestamp: u64) {
+            test::set_block_timestamp::<DefaultEnvironment>(timestamp);
         }
 
-        // 36 🟢 View My Seller Account - ORDERS - DELIVERED
-        #[ink(message)]
-        pub fn view_my_seller_orders_delivered (&self) -> Vec<Order> {
-            // set the caller
-            let caller = Self::env().caller();
-            // set up return structures
-            let mut store_orders = <Vec<Order>>::default();
+        // 7 day escrow timeout, 10 day delivery window, no platform fee
+        fn new_contract() -> ContractStorage {
+            set_now(DAY);
+            set_caller(accounts().alice);
+            ContractStorage::new(7 * DAY, 10 * DAY, 0, accounts().alice).unwrap()
+        }
 
-            // get the seller's orders from account_seller_orders: Mapping<AccountId, HashVector>
-            let order_ids2 = self.account_seller_orders_2delivered.get(caller).unwrap_or_default();
-            for id in order_ids2.hashvector.iter() {
-                // get the order details and add it to the store_orders vector order_details: Mapping<Hash, Order>
-                let orderdetails = self.order_details.get(id).unwrap_or_default();
-                store_orders.push(orderdetails);
-            }
+        // bob lists a product and gets back its id
+        fn list_product(contract: &mut ContractStorage, digital: bool, price: Balance, zeno_percent: u128, handling_time: u64) -> Hash {
+            set_caller(accounts().bob);
+            contract.add_a_product(digital, b"lamp".to_vec(), price, b"brand".to_vec(), b"home".to_vec(),
+                b"a lamp".to_vec(), 10, Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new(),
+                Vec::new(), Vec::new(), zeno_percent, handling_time).unwrap();
+            *contract.account_seller_products.get(accounts().bob).unwrap_or_default().hashvector.last().unwrap()
+        }
 
-            // return the results
-            store_orders
+        // the buyer checks out one of the item, paying exactly the price
+        fn buy(contract: &mut ContractStorage, buyer: AccountId, item_id: Hash, price: Balance) -> Hash {
+            set_caller(buyer);
+            contract.add_item_to_cart(item_id, 1).unwrap();
+            test::set_value_transferred::<DefaultEnvironment>(price);
+            let receipt = contract.checkout_cart(b"1 main st".to_vec(), None, None).unwrap();
+            receipt.order_ids[0]
         }
 
-        // 37 🟢 View My Seller Account - ORDERS - RESOLVED
-        #[ink(message)]
-        pub fn view_my_seller_orders_resolved (&self) -> Vec<Order> {
-            // set the caller
-            let caller = Self::env().caller();
-            // set up return structures
-            let mut store_orders = <Vec<Order>>::default();
+        fn ship_and_deliver(contract: &mut ContractStorage, order_id: Hash) {
+            set_caller(accounts().bob);
+            contract.update_order_tracking_information(order_id, b"track".to_vec(), true, false).unwrap();
+            contract.update_order_tracking_information(order_id, b"track".to_vec(), false, true).unwrap();
+        }
 
-            // get the seller's orders from account_seller_orders: Mapping<AccountId, HashVector>
-            let order_ids3 = self.account_seller_orders_3resolved.get(caller).unwrap_or_default();
-            for id in order_ids3.hashvector.iter() {
-                // get the order details and add it to the store_orders vector order_details: Mapping<Hash, Order>
-                let orderdetails = self.order_details.get(id).unwrap_or_default();
-                store_orders.push(orderdetails);
-            }
+        // charlie reports a delivered physical order damaged and bob denies it
+        fn deny_damaged(contract: &mut ContractStorage, order_id: Hash) {
+            set_caller(accounts().charlie);
+            contract.report_problem(order_id, ProblemKind::Damaged, Vec::new(), b"broken".to_vec()).unwrap();
+            set_caller(accounts().bob);
+            contract.deny_resolution_request(order_id).unwrap();
+        }
 
-            // return the results
-            store_orders
+        fn deposit_bond(contract: &mut ContractStorage, amount: Balance) {
+            set_caller(accounts().bob);
+            test::set_value_transferred::<DefaultEnvironment>(amount);
+            contract.deposit_bond().unwrap();
+            test::set_value_transferred::<DefaultEnvironment>(0);
         }
 
-        // 38 🟢 View My Seller Account - ORDERS - PROBLEM
-        #[ink(message)]
-        pub fn view_my_seller_orders_problem (&self) -> Vec<Order> {
-            // set the caller
-            let caller = Self::env().caller();
-            // set up return structures
-            let mut store_orders = <Vec<Order>>::default();
+        #[ink::test]
+        fn zeno_settings_lock_once_a_slot_is_filled() {
+            let mut contract = new_contract();
+            let item = list_product(&mut contract, true, 1000, 10, 0);
+            set_caller(accounts().bob);
+            assert_eq!(contract.update_zeno_settings(item, 5, 1, false), Ok(()));
+
+            buy(&mut contract, accounts().charlie, item, 1000);
+            set_caller(accounts().bob);
+            assert_eq!(contract.update_zeno_settings(item, 100, 1, false), Err(Error::InvalidZenoSettings));
+            assert_eq!(contract.update_zeno_settings(item, 5, 2, false), Err(Error::InvalidZenoSettings));
+            // the repeat buyer rule can still change
+            assert_eq!(contract.update_zeno_settings(item, 5, 1, true), Ok(()));
+        }
 
-            // get the seller's orders from account_seller_orders: Mapping<AccountId, HashVector>
-            let order_ids4 = self.account_seller_orders_4problem.get(caller).unwrap_or_default();
-            for id in order_ids4.hashvector.iter() {
-                // get the order details and add it to the store_orders vector order_details: Mapping<Hash, Order>
-                let orderdetails = self.order_details.get(id).unwrap_or_default();
-                store_orders.push(orderdetails);
+        #[ink::test]
+        fn orders_keep_the_zeno_settings_from_checkout() {
+            let mut contract = new_contract();
+            let item = list_product(&mut contract, false, 1000, 10, 0);
+            set_caller(accounts().bob);
+            contract.update_zeno_settings(item, 4, 2, false).unwrap();
+            // django fills the first slot, charlie's order pays that slot
+            buy(&mut contract, accounts().django, item, 1000);
+            let order_id = buy(&mut contract, accounts().charlie, item, 1000);
+            let details = contract.order_details.get(order_id).unwrap();
+            assert_eq!((details.zeno_max_buyers, details.zeno_curve), (4, 2));
+
+            // equal split over 4 slots, slots 1 and 2 are filled by django and charlie
+            ship_and_deliver(&mut contract, order_id);
+            set_caller(accounts().charlie);
+            contract.release_escrow(order_id).unwrap();
+            assert_eq!(contract.account_zeno_earnings_total.get(accounts().django).unwrap_or_default(), 25);
+        }
+
+        #[ink::test]
+        fn finalize_reaches_delivered_orders_past_the_delivered_list() {
+            let mut contract = new_contract();
+            let mut items = Vec::new();
+            for n in 0..8 {
+                set_now(DAY + n);
+                items.push(list_product(&mut contract, false, 100, 0, 0));
+            }
+            set_now(2 * DAY);
+            let mut orders = Vec::new();
+            for n in 0..71 {
+                let order_id = buy(&mut contract, accounts().charlie, items[n / 10], 100);
+                ship_and_deliver(&mut contract, order_id);
+                orders.push(order_id);
             }
+            // the oldest delivered order fell off the seller's delivered list
+            let delivered = contract.account_seller_orders_2delivered.get(accounts().bob).unwrap_or_default();
+            assert!(!delivered.hashvector.contains(&orders[0]));
+
+            set_now(20 * DAY);
+            set_caller(accounts().django);
+            contract.finalize_expired_orders(accounts().bob).unwrap();
+            assert_eq!(contract.order_details.get(orders[0]).unwrap().order_status, OrderStatus::Complete);
+            assert_eq!(contract.view_order_escrow(orders[0]), 0);
+            assert_eq!(contract.claimable_balance.get(accounts().bob).unwrap_or_default(), 7100);
+        }
 
-            // return the results
-            store_orders
+        #[ink::test]
+        fn unscheduled_services_close_their_problem_window() {
+            let mut contract = new_contract();
+            set_caller(accounts().bob);
+            contract.add_a_service(true, b"lesson".to_vec(), 500, b"music".to_vec(), b"a lesson".to_vec(),
+                10, Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new(), 0).unwrap();
+            let service = contract.account_seller_services.get(accounts().bob).unwrap_or_default().hashvector[0];
+            let order_id = buy(&mut contract, accounts().charlie, service, 500);
+
+            // bob never schedules it, the default 24 hour window runs from the order
+            set_now(2 * DAY + 1);
+            set_caller(accounts().charlie);
+            assert_eq!(contract.report_problem(order_id, ProblemKind::ServiceNotRendered, Vec::new(), Vec::new()),
+                Err(Error::NotEligibleToReport));
+            contract.finalize_expired_orders(accounts().bob).unwrap();
+            assert_eq!(contract.order_details.get(order_id).unwrap().order_status, OrderStatus::Complete);
         }
 
-        // 39 🟢 View My Seller Account - ORDERS - REFUSED
-        #[ink(message)]
-        pub fn view_my_seller_orders_refused (&self) -> Vec<Order> {
-            // set the caller
-            let caller = Self::env().caller();
-            // set up return structures
-            let mut store_orders = <Vec<Order>>::default();
+        #[ink::test]
+        fn checkout_holds_payment_in_escrow_until_release() {
+            let mut contract = new_contract();
+            let lamp = list_product(&mut contract, false, 1000, 0, 0);
+            set_now(2 * DAY);
+            let chair = list_product(&mut contract, false, 500, 0, 0);
+            let first = buy(&mut contract, accounts().charlie, lamp, 1000);
+            let second = buy(&mut contract, accounts().charlie, chair, 500);
+
+            let escrow = contract.view_escrow_by_seller(accounts().bob);
+            assert_eq!(escrow.total_escrowed, 1500);
+            assert_eq!(escrow.orders.len(), 2);
+            assert_eq!(contract.view_order_escrow(first), 1000);
+
+            // nothing is released before the order ships
+            set_caller(accounts().charlie);
+            assert_eq!(contract.release_escrow(first), Err(Error::EscrowLocked));
+            set_caller(accounts().bob);
+            contract.update_order_tracking_information(first, b"track".to_vec(), true, false).unwrap();
+            set_caller(accounts().django);
+            assert_eq!(contract.release_escrow(first), Err(Error::EscrowLocked));
+            set_caller(accounts().charlie);
+            contract.release_escrow(first).unwrap();
+            assert_eq!(contract.release_escrow(first), Err(Error::NothingInEscrow));
+            assert_eq!(contract.claimable_balance.get(accounts().bob).unwrap_or_default(), 1000);
+
+            // completing the other order pays it out and empties the seller's escrow list
+            ship_and_deliver(&mut contract, second);
+            set_caller(accounts().charlie);
+            contract.confirm_order_received(second).unwrap();
+            assert_eq!(contract.claimable_balance.get(accounts().bob).unwrap_or_default(), 1500);
+            let escrow = contract.view_escrow_by_seller(accounts().bob);
+            assert_eq!(escrow.total_escrowed, 0);
+            assert!(escrow.orders.is_empty());
+        }
 
-            // get the seller's orders from account_seller_orders: Mapping<AccountId, HashVector>
-            let order_ids5 = self.account_seller_orders_5refused.get(caller).unwrap_or_default();
-            for id in order_ids5.hashvector.iter() {
-                // get the order details and add it to the store_orders vector order_details: Mapping<Hash, Order>
-                let orderdetails = self.order_details.get(id).unwrap_or_default();
-                store_orders.push(orderdetails);
-            }
+        #[ink::test]
+        fn sellers_withdraw_what_a_sale_credited_them() {
+            let mut contract = new_contract();
+            let download = list_product(&mut contract, true, 1000, 0, 0);
+            set_caller(accounts().bob);
+            assert_eq!(contract.withdraw(), Err(Error::NothingToWithdraw));
+
+            // digital orders pay the seller at checkout
+            buy(&mut contract, accounts().eve, download, 1000);
+            assert_eq!(contract.view_claimable_balance(accounts().bob), 1000);
+
+            test::set_account_balance::<DefaultEnvironment>(test::callee::<DefaultEnvironment>(), 1000);
+            let before = test::get_account_balance::<DefaultEnvironment>(accounts().bob).unwrap();
+            set_caller(accounts().bob);
+            contract.withdraw().unwrap();
+            assert_eq!(test::get_account_balance::<DefaultEnvironment>(accounts().bob).unwrap(), before + 1000);
+            assert_eq!(contract.view_claimable_balance(accounts().bob), 0);
+            assert_eq!(contract.withdraw(), Err(Error::NothingToWithdraw));
+        }
 
-            // return the results
-            store_orders
+        #[ink::test]
+        fn buyers_can_only_cancel_inside_the_cancellation_window() {
+            let mut contract = new_contract();
+            let item = list_product(&mut contract, false, 1000, 0, 0);
+            set_caller(accounts().bob);
+            contract.set_cancellation_window(DAY).unwrap();
+            let early = buy(&mut contract, accounts().charlie, item, 1000);
+            set_now(2 * DAY);
+            let late = buy(&mut contract, accounts().charlie, item, 1000);
+            assert_eq!(contract.product_details.get(item).unwrap().inventory, 8);
+
+            // half a day after the first order, it can still be cancelled
+            set_now(DAY + DAY / 2);
+            set_caller(accounts().bob);
+            assert_eq!(contract.cancel_order(early), Err(Error::NotYourOrder));
+            set_caller(accounts().charlie);
+            contract.cancel_order(early).unwrap();
+            assert_eq!(contract.order_details.get(early).unwrap().order_status, OrderStatus::Cancelled);
+            assert_eq!(contract.claimable_balance.get(accounts().charlie).unwrap_or_default(), 1000);
+            assert_eq!(contract.view_order_escrow(early), 0);
+            assert_eq!(contract.product_details.get(item).unwrap().inventory, 9);
+
+            // the second order's window closed a day after it was placed
+            set_now(3 * DAY + 1);
+            assert_eq!(contract.cancel_order(late), Err(Error::CannotCancel));
+            assert_eq!(contract.order_details.get(late).unwrap().order_status, OrderStatus::Awaiting);
+            assert_eq!(contract.view_order_escrow(late), 1000);
         }
 
+        #[ink::test]
+        fn arbiter_refund_falls_back_from_escrow_to_bond_to_balance() {
+            let mut contract = new_contract();
+            set_caller(accounts().alice);
+            contract.add_arbiter(accounts().django).unwrap();
+            deposit_bond(&mut contract, 300);
+            // a download sale leaves 1000 in bob's claimable balance
+            let download = list_product(&mut contract, true, 1000, 0, 0);
+            buy(&mut contract, accounts().eve, download, 1000);
+
+            // charlie releases the escrow early, then reports a problem that bob denies
+            set_now(2 * DAY);
+            let item = list_product(&mut contract, false, 1000, 0, 0);
+            let order_id = buy(&mut contract, accounts().charlie, item, 1000);
+            ship_and_deliver(&mut contract, order_id);
+            set_caller(accounts().charlie);
+            contract.release_escrow(order_id).unwrap();
+            assert_eq!(contract.claimable_balance.get(accounts().bob).unwrap_or_default(), 2000);
+            deny_damaged(&mut contract, order_id);
+            set_caller(accounts().django);
+            assert_eq!(contract.arbiter_rule_refund(order_id, 800), Err(Error::NotInArbitration));
+            set_caller(accounts().charlie);
+            contract.escalate_dispute(order_id).unwrap();
+
+            // nothing is left in escrow, so the bond pays 300 and bob's balance the other 500
+            set_caller(accounts().bob);
+            assert_eq!(contract.arbiter_rule_refund(order_id, 800), Err(Error::NotArbiter));
+            set_caller(accounts().django);
+            assert_eq!(contract.arbiter_rule_refund(order_id, 1001), Err(Error::InvalidRefundAmount));
+            contract.arbiter_rule_refund(order_id, 800).unwrap();
+            assert_eq!(contract.claimable_balance.get(accounts().charlie).unwrap_or_default(), 800);
+            assert_eq!(contract.account_profile_seller.get(accounts().bob).unwrap().bond, 0);
+            assert_eq!(contract.claimable_balance.get(accounts().bob).unwrap_or_default(), 1500);
+            let details = contract.order_details.get(order_id).unwrap();
+            assert_eq!(details.arbitration, Arbitration::RuledRefund);
+            assert_eq!(details.refunded_amount, 800);
+            assert!(contract.view_open_disputes().is_empty());
+        }
 
-        // 40 🟢 Get Market Statistics
-        // get various stats about all sellers, all buyers, all products, all services, all orders for analysis
-        #[ink(message)]
-        pub fn get_market_statistics (&self) -> MarketStatistics {
-            // set the caller
-            let caller = Self::env().caller();
-            let rightnow = self.env().block_timestamp();
-            // set up return structures
-            let seller_count = self.all_sellers.len().try_into().unwrap();
-            let buyer_count = self.total_count_buyers;
-            let product_count = self.total_count_products;
-            let service_count = self.total_count_services;
-            let order_count = self.total_count_orders;
-            let mut orderdata = <Vec<OrderData>>::default();
-            
-            // for each order id, get the details from order_details: Mapping<Hash, Order>
-            for id in self.all_orders.iter() {
-                let details = self.order_details.get(id).unwrap_or_default();
-                let data = OrderData {
-                    timestamp: details.order_timestamp,
-                    total: details.total_order_price,
-                    status: details.order_status,
-                    problem: details.problem,
-                    resolution: details.resolution,
-                };
-                orderdata.push(data);
-            }
+        #[ink::test]
+        fn returned_items_are_refunded_and_restocked() {
+            let mut contract = new_contract();
+            let item = list_product(&mut contract, false, 1000, 0, 0);
+            let order_id = buy(&mut contract, accounts().charlie, item, 1000);
+            ship_and_deliver(&mut contract, order_id);
+            set_caller(accounts().charlie);
+            contract.report_problem(order_id, ProblemKind::Damaged, Vec::new(), b"broken".to_vec()).unwrap();
+
+            // escrow still holds the full price, so bob sends nothing
+            set_caller(accounts().bob);
+            test::set_value_transferred::<DefaultEnvironment>(1);
+            assert_eq!(contract.authorize_return(order_id, b"2 main st".to_vec()), Err(Error::InvalidRefundAmount));
+            set_caller(accounts().bob);
+            contract.authorize_return(order_id, b"2 main st".to_vec()).unwrap();
+            assert_eq!(contract.submit_return_tracking(order_id, b"back".to_vec()), Err(Error::NotYourOrder));
+
+            set_now(2 * DAY);
+            set_caller(accounts().charlie);
+            contract.submit_return_tracking(order_id, b"back".to_vec()).unwrap();
+            assert_eq!(contract.order_details.get(order_id).unwrap().order_status, OrderStatus::ReturnShipped);
+
+            // bob never confirms, so anyone can once the delivery window has passed
+            set_caller(accounts().django);
+            assert_eq!(contract.confirm_return_received(order_id), Err(Error::ReturnNotExpired));
+            set_now(12 * DAY + 1);
+            contract.confirm_return_received(order_id).unwrap();
+            let details = contract.order_details.get(order_id).unwrap();
+            assert_eq!(details.resolution, Resolution::ReturnedAndRefunded);
+            assert!(details.restocked);
+            assert_eq!(contract.claimable_balance.get(accounts().charlie).unwrap_or_default(), 1000);
+            assert_eq!(contract.claimable_balance.get(accounts().bob).unwrap_or_default(), 0);
+            assert_eq!(contract.view_order_escrow(order_id), 0);
+            assert_eq!(contract.product_details.get(item).unwrap().inventory, 10);
+        }
 
-            // package the results
-            let stats = MarketStatistics {
-                called_by: caller,
-                timestamp: rightnow,
-                number_of_sellers: seller_count,
-                number_of_buyers: buyer_count,
-                number_of_products: product_count,
-                number_of_services: service_count,
-                number_of_orders: order_count,
-                orders: orderdata,
-            };
+        #[ink::test]
+        fn completed_orders_cannot_move_back() {
+            let mut contract = new_contract();
+            let item = list_product(&mut contract, false, 1000, 0, 0);
+            let order_id = buy(&mut contract, accounts().charlie, item, 1000);
+            ship_and_deliver(&mut contract, order_id);
+            set_caller(accounts().charlie);
+            contract.confirm_order_received(order_id).unwrap();
+            assert_eq!(contract.cancel_order(order_id), Err(Error::CannotCancel));
+
+            // the status table rejects the move even when a message's own checks are skipped
+            let mut details = contract.order_details.get(order_id).unwrap();
+            let entries = details.timeline.len();
+            assert_eq!(contract.transition(&mut details, OrderStatus::Shipped, accounts().bob, b"reship"), Err(Error::IllegalTransition));
+            assert_eq!(details.order_status, OrderStatus::Complete);
+            assert_eq!(details.timeline.len(), entries);
+            assert!(!ContractStorage::can_transition(OrderStatus::Complete, OrderStatus::Problem));
+            assert!(!ContractStorage::can_transition(OrderStatus::Awaiting, OrderStatus::Delivered));
+        }
 
-            // return the results
-            stats
+        #[ink::test]
+        fn pausing_checkout_lets_existing_orders_finish() {
+            let mut contract = new_contract();
+            let item = list_product(&mut contract, false, 1000, 0, 0);
+            let order_id = buy(&mut contract, accounts().charlie, item, 1000);
+            set_caller(accounts().bob);
+            assert_eq!(contract.set_pause(true, true), Err(Error::NotAdmin));
+
+            set_caller(accounts().alice);
+            contract.set_pause(false, true).unwrap();
+            set_caller(accounts().charlie);
+            contract.add_item_to_cart(item, 1).unwrap();
+            test::set_value_transferred::<DefaultEnvironment>(1000);
+            assert_eq!(contract.checkout_cart(b"1 main st".to_vec(), None, None).err(), Some(Error::Paused));
+            set_caller(accounts().bob);
+            contract.update_order_tracking_information(order_id, b"track".to_vec(), true, false).unwrap();
+
+            // pausing everything stops the existing order too
+            set_caller(accounts().alice);
+            contract.set_pause(true, false).unwrap();
+            set_caller(accounts().bob);
+            assert_eq!(contract.update_order_tracking_information(order_id, b"track".to_vec(), false, true), Err(Error::Paused));
+            set_caller(accounts().alice);
+            contract.set_pause(false, false).unwrap();
+            set_caller(accounts().bob);
+            contract.update_order_tracking_information(order_id, b"track".to_vec(), false, true).unwrap();
         }
 
+        #[ink::test]
+        fn only_the_proposed_admin_can_accept() {
+            let mut contract = new_contract();
+            set_caller(accounts().bob);
+            assert_eq!(contract.propose_admin(accounts().bob), Err(Error::NotAdmin));
+            assert_eq!(contract.accept_admin(), Err(Error::NotPendingAdmin));
+
+            set_caller(accounts().alice);
+            contract.propose_admin(accounts().django).unwrap();
+            set_caller(accounts().eve);
+            assert_eq!(contract.accept_admin(), Err(Error::NotPendingAdmin));
+            assert_eq!(contract.view_admin_settings().0, accounts().alice);
+
+            set_caller(accounts().django);
+            contract.accept_admin().unwrap();
+            assert_eq!(contract.view_admin_settings(), (accounts().django, None, false, false));
+            set_caller(accounts().alice);
+            assert_eq!(contract.set_pause(true, true), Err(Error::NotAdmin));
+        }
 
-        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-        // >>>>>>>>>>>>>>>>>>>>>>>>>> SECONDARY MESSAGES <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        // >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        #[ink::test]
+        fn delivered_escrow_stays_locked_through_the_dispute_window() {
+            let mut contract = new_contract();
+            set_caller(accounts().bob);
+            contract.set_dispute_window(10 * DAY).unwrap();
+            let item = list_product(&mut contract, false, 1000, 0, 0);
+            let order_id = buy(&mut contract, accounts().charlie, item, 1000);
+            assert_eq!(contract.order_details.get(order_id).unwrap().dispute_window, 10 * DAY);
+
+            // shipped on day 2, delivered on day 3
+            set_now(2 * DAY);
+            set_caller(accounts().bob);
+            contract.update_order_tracking_information(order_id, b"track".to_vec(), true, false).unwrap();
+            set_now(3 * DAY);
+            contract.update_order_tracking_information(order_id, b"track".to_vec(), false, true).unwrap();
+
+            // the escrow timeout has passed but the buyer can still report a problem
+            set_now(10 * DAY);
+            set_caller(accounts().django);
+            assert_eq!(contract.release_escrow(order_id), Err(Error::EscrowLocked));
+            set_now(13 * DAY);
+            assert_eq!(contract.release_escrow(order_id), Ok(()));
+            assert_eq!(contract.claimable_balance.get(accounts().bob).unwrap_or_default(), 1000);
+        }
 
+        #[ink::test]
+        fn bond_is_held_while_a_denial_can_be_escalated() {
+            let mut contract = new_contract();
+            let item = list_product(&mut contract, false, 1000, 0, 0);
+            deposit_bond(&mut contract, 500);
+            contract.request_bond_withdrawal().unwrap();
+
+            let order_id = buy(&mut contract, accounts().charlie, item, 1000);
+            set_now(15 * DAY);
+            ship_and_deliver(&mut contract, order_id);
+            deny_damaged(&mut contract, order_id);
+
+            // the cooldown has passed, but charlie can still escalate the denial
+            assert_eq!(contract.withdraw_bond(), Err(Error::OpenOrders));
+            set_now(16 * DAY + 1);
+            assert_eq!(contract.withdraw_bond(), Ok(()));
+            assert_eq!(contract.claimable_balance.get(accounts().bob).unwrap_or_default(), 500);
+        }
 
-        // 41 🟢 Verify That An Account Has Set Up Buyer and/or Seller Info
-        #[ink(message)]
-        pub fn verify_account (&self, verify: AccountId) -> (u8, u8) {
-            // set up return structures
-            let mut buyer: u8 = 0;
-            let mut seller: u8 = 0;
-            // check the account_profile_buyer map
-            if self.account_profile_buyer.contains(verify) {
-                buyer = 1;
-            }
-            if self.account_profile_seller.contains(verify) {
-                seller = 1;
+        #[ink::test]
+        fn abandon_penalty_is_set_by_the_admin() {
+            let mut contract = new_contract();
+            let item = list_product(&mut contract, false, 1000, 0, DAY);
+            deposit_bond(&mut contract, 500);
+
+            // no penalty until the admin sets one
+            let first = buy(&mut contract, accounts().charlie, item, 1000);
+            set_now(3 * DAY);
+            contract.refund_unshipped_order(first).unwrap();
+            assert_eq!(contract.claimable_balance.get(accounts().charlie).unwrap_or_default(), 1000);
+            assert_eq!(contract.account_profile_seller.get(accounts().bob).unwrap().bond, 500);
+
+            set_caller(accounts().bob);
+            assert_eq!(contract.set_abandon_penalty(1000), Err(Error::NotAdmin));
+            set_caller(accounts().alice);
+            assert_eq!(contract.set_abandon_penalty(10001), Err(Error::FeeTooHigh));
+            contract.set_abandon_penalty(1000).unwrap();
+
+            // 10% of the order price comes out of the bond
+            let second = buy(&mut contract, accounts().charlie, item, 1000);
+            set_now(5 * DAY);
+            contract.refund_unshipped_order(second).unwrap();
+            assert_eq!(contract.claimable_balance.get(accounts().charlie).unwrap_or_default(), 2100);
+            assert_eq!(contract.account_profile_seller.get(accounts().bob).unwrap().bond, 400);
+            assert_eq!(contract.account_profile_buyer.get(accounts().charlie).unwrap().total_refused, 2);
+        }
+
+        // 10% of each sale goes to the pool, claims capped at 300 per order and 500 per buyer,
+        // then eve buys a digital product for 1000 which puts 100 in the pool
+        fn funded_pool() -> ContractStorage {
+            let mut contract = new_contract();
+            set_caller(accounts().alice);
+            contract.set_protection_settings(1000, 300, 500).unwrap();
+            contract.add_arbiter(accounts().django).unwrap();
+            let download = list_product(&mut contract, true, 1000, 0, 0);
+            buy(&mut contract, accounts().eve, download, 1000);
+            assert_eq!(contract.view_protection_pool().balance, 100);
+            contract
+        }
+
+        // charlie buys a physical product that bob then denies a problem on
+        fn denied_order(contract: &mut ContractStorage) -> Hash {
+            let item = list_product(contract, false, 1000, 0, 0);
+            let order_id = buy(contract, accounts().charlie, item, 1000);
+            ship_and_deliver(contract, order_id);
+            deny_damaged(contract, order_id);
+            order_id
+        }
+
+        #[ink::test]
+        fn protection_claim_is_paid_from_the_pool() {
+            let mut contract = funded_pool();
+            let order_id = denied_order(&mut contract);
+
+            set_caller(accounts().charlie);
+            assert_eq!(contract.file_protection_claim(order_id, 400), Err(Error::CannotClaim));
+            contract.file_protection_claim(order_id, 200).unwrap();
+            assert_eq!(contract.file_protection_claim(order_id, 200), Err(Error::CannotClaim));
+
+            set_caller(accounts().bob);
+            assert_eq!(contract.approve_protection_claim(order_id), Err(Error::NotAdmin));
+            // the pool only holds 100
+            set_caller(accounts().alice);
+            contract.approve_protection_claim(order_id).unwrap();
+            assert_eq!(contract.claimable_balance.get(accounts().charlie).unwrap_or_default(), 100);
+            assert_eq!(contract.approve_protection_claim(order_id), Err(Error::NoPendingClaim));
+
+            let pool = contract.view_protection_pool();
+            assert_eq!((pool.balance, pool.total_funded, pool.total_paid), (0, 100, 100));
+            assert_eq!(pool.claims[0].status, ClaimStatus::Approved);
+            assert_eq!(pool.claims[0].amount_paid, 100);
+        }
+
+        #[ink::test]
+        fn claimed_orders_cannot_be_escalated() {
+            let mut contract = funded_pool();
+            let order_id = denied_order(&mut contract);
+
+            set_caller(accounts().charlie);
+            contract.file_protection_claim(order_id, 100).unwrap();
+            assert_eq!(contract.escalate_dispute(order_id), Err(Error::CannotEscalate));
+            set_caller(accounts().alice);
+            contract.approve_protection_claim(order_id).unwrap();
+            set_caller(accounts().charlie);
+            assert_eq!(contract.escalate_dispute(order_id), Err(Error::CannotEscalate));
+        }
+
+        #[ink::test]
+        fn rejected_claims_can_still_be_escalated() {
+            let mut contract = funded_pool();
+            let order_id = denied_order(&mut contract);
+
+            set_caller(accounts().charlie);
+            contract.file_protection_claim(order_id, 100).unwrap();
+            set_caller(accounts().alice);
+            contract.reject_protection_claim(order_id).unwrap();
+            assert_eq!(contract.protection_claims.get(order_id).unwrap().status, ClaimStatus::Rejected);
+            set_caller(accounts().charlie);
+            assert_eq!(contract.escalate_dispute(order_id), Ok(()));
+        }
+
+        #[ink::test]
+        fn escalated_orders_cannot_be_claimed() {
+            let mut contract = funded_pool();
+            let order_id = denied_order(&mut contract);
+
+            set_caller(accounts().charlie);
+            contract.escalate_dispute(order_id).unwrap();
+            assert_eq!(contract.file_protection_claim(order_id, 100), Err(Error::CannotClaim));
+            set_caller(accounts().django);
+            contract.arbiter_rule_refund(order_id, 1000).unwrap();
+            set_caller(accounts().charlie);
+            assert_eq!(contract.file_protection_claim(order_id, 100), Err(Error::CannotClaim));
+        }
+
+        #[ink::test]
+        fn arbiter_refunded_orders_cannot_be_claimed() {
+            let mut contract = funded_pool();
+            let order_id = denied_order(&mut contract);
+
+            // the claim is rejected, so charlie takes the denial to an arbiter instead
+            set_caller(accounts().charlie);
+            contract.file_protection_claim(order_id, 100).unwrap();
+            set_caller(accounts().alice);
+            contract.reject_protection_claim(order_id).unwrap();
+            set_caller(accounts().charlie);
+            contract.escalate_dispute(order_id).unwrap();
+            set_caller(accounts().django);
+            contract.arbiter_rule_refund(order_id, 1000).unwrap();
+            assert_eq!(contract.claimable_balance.get(accounts().charlie).unwrap_or_default(), 1000);
+
+            // the refunded order cannot be claimed again or paid from the pool
+            set_caller(accounts().charlie);
+            assert_eq!(contract.file_protection_claim(order_id, 100), Err(Error::CannotClaim));
+            set_caller(accounts().alice);
+            assert_eq!(contract.approve_protection_claim(order_id), Err(Error::NoPendingClaim));
+            assert_eq!(contract.view_protection_pool().balance, 100);
+        }
+
+        #[ink::test]
+        fn trimming_claim_history_keeps_claim_records() {
+            let mut contract = funded_pool();
+            set_caller(accounts().bob);
+            contract.add_a_product(false, b"lamp".to_vec(), 10, b"brand".to_vec(), b"home".to_vec(),
+                b"a lamp".to_vec(), 300, Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new(),
+                Vec::new(), Vec::new(), 0, 0).unwrap();
+            let item = *contract.account_seller_products.get(accounts().bob).unwrap_or_default().hashvector.last().unwrap();
+
+            // 201 buyers each get a denial and claim 1, the last claim pushes the first out of the history
+            let mut claimed = Vec::new();
+            for n in 10..=210u8 {
+                let buyer = AccountId::from([n; 32]);
+                let order_id = buy(&mut contract, buyer, item, 10);
+                ship_and_deliver(&mut contract, order_id);
+                set_caller(buyer);
+                contract.report_problem(order_id, ProblemKind::Damaged, Vec::new(), b"broken".to_vec()).unwrap();
+                set_caller(accounts().bob);
+                contract.deny_resolution_request(order_id).unwrap();
+                set_caller(buyer);
+                contract.file_protection_claim(order_id, 1).unwrap();
+                claimed.push(order_id);
             }
-            // return the results
-            let result: (u8, u8) = (buyer, seller);
-            result
+            let first = claimed[0];
+            let pool = contract.view_protection_pool();
+            assert_eq!(pool.claims.len(), 200);
+            assert!(pool.claims.iter().all(|claim| claim.order_id != first));
+
+            // the first claim can still be decided and cannot be filed again
+            set_caller(AccountId::from([10; 32]));
+            assert_eq!(contract.file_protection_claim(first, 1), Err(Error::CannotClaim));
+            set_caller(accounts().alice);
+            assert_eq!(contract.approve_protection_claim(first), Ok(()));
+            assert_eq!(contract.claimable_balance.get(AccountId::from([10; 32])).unwrap_or_default(), 1);
         }
 
-        
+        #[ink::test]
+        fn platform_fee_and_protection_share_one_cap() {
+            let mut contract = new_contract();
+            set_caller(accounts().alice);
+            contract.set_protection_settings(3000, 0, 0).unwrap();
+            assert_eq!(contract.set_platform_fee(8000, accounts().alice), Err(Error::FeeTooHigh));
+            assert_eq!(contract.set_platform_fee(7000, accounts().alice), Ok(()));
+            assert_eq!(contract.set_protection_settings(3001, 0, 0), Err(Error::FeeTooHigh));
+        }
 
-        // END OF MESSAGE LIST
+        #[ink::test]
+        fn deploying_with_a_fee_over_100_percent_fails() {
+            set_caller(accounts().alice);
+            assert_eq!(ContractStorage::new(7 * DAY, 10 * DAY, 10001, accounts().alice).err(), Some(Error::FeeTooHigh));
+            let contract = ContractStorage::new(7 * DAY, 10 * DAY, 10000, accounts().alice).unwrap();
+            assert_eq!(contract.view_platform_fees().platform_fee_bps, 10000);
+        }
 
+        #[ink::test]
+        fn partial_refund_pays_the_seller_the_rest_minus_its_share_of_fees() {
+            let mut contract = new_contract();
+            set_caller(accounts().alice);
+            contract.set_platform_fee(1000, accounts().alice).unwrap();
+            let item = list_product(&mut contract, false, 1000, 0, 0);
+            let order_id = buy(&mut contract, accounts().charlie, item, 1000);
+            assert_eq!(contract.order_details.get(order_id).unwrap().platform_fee, 100);
+            ship_and_deliver(&mut contract, order_id);
+            set_caller(accounts().charlie);
+            contract.report_problem(order_id, ProblemKind::Damaged, Vec::new(), b"broken".to_vec()).unwrap();
+
+            // escrow covers the offer so bob sends nothing, the buyer takes 400 back
+            set_caller(accounts().bob);
+            contract.issue_refund(order_id, 400).unwrap();
+            assert_eq!(contract.claimable_balance.get(accounts().charlie).unwrap_or_default(), 0);
+            set_caller(accounts().charlie);
+            contract.accept_refund_offer(order_id).unwrap();
+
+            // the other 600 is paid out carrying 60% of the platform fee
+            assert_eq!(contract.claimable_balance.get(accounts().charlie).unwrap_or_default(), 400);
+            assert_eq!(contract.claimable_balance.get(accounts().bob).unwrap_or_default(), 540);
+            assert_eq!(contract.view_platform_fees().total_accrued, 60);
+            assert_eq!(contract.view_order_escrow(order_id), 0);
+        }
     }
-    // END OF CONTRACT STORAGE
 
 }